@@ -0,0 +1,190 @@
+use super::super::{Contest, LogEntry, Roller};
+use super::{Api, ApiResponse};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Instant;
+
+/// largest pool whose distribution is worked out, the server answering one request at a time
+const MAX_DISTRIBUTION_DICE: u64 = 100;
+
+#[derive(Deserialize)]
+/// body of the roll and distribution requests
+struct RollRequest {
+  definition: String,
+  seed: Option<u64>,
+}
+
+impl Api {
+  /// Answers a request from the client, identified for rate limiting by its address
+  pub fn handle(
+    &mut self,
+    method: &str,
+    path: &str,
+    body: &str,
+    client: &str,
+    now: Instant,
+  ) -> ApiResponse {
+    if method == "OPTIONS" {
+      return self.respond(204, String::new());
+    }
+
+    if let Some(limiter) = &mut self.limiter {
+      if !limiter.allow(client, now) {
+        let mut response = self.error(429, "too many requests");
+        response
+          .headers
+          .push((String::from("Retry-After"), String::from("1")));
+        return response;
+      }
+    }
+
+    let segments: Vec<&str> = path
+      .split('?')
+      .next()
+      .unwrap_or_default()
+      .split('/')
+      .filter(|x| !x.is_empty())
+      .collect();
+
+    match (method, segments.as_slice()) {
+      ("POST", ["roll"]) => self.roll(body),
+      ("POST", ["distribution"]) => self.distribution(body),
+      ("GET", ["tables"]) => self.json(200, &json!({ "tables": self.tables.names() })),
+      ("POST", ["tables", name]) => match self.tables.roll(name) {
+        Ok(result) => self.json(200, &result),
+        Err(err) => self.error(404, &format!("{:?}", err)),
+      },
+      (_, ["roll"] | ["distribution"] | ["tables"] | ["tables", _]) => {
+        self.error(405, "method not allowed")
+      }
+      _ => self.error(404, "not found"),
+    }
+  }
+
+  /// throws a definition, or a contest
+  fn roll(&mut self, body: &str) -> ApiResponse {
+    let (request, resolved) = match self.request(body) {
+      Ok(request) => request,
+      Err(response) => return response,
+    };
+    let seed = request.seed.unwrap_or_else(|| self.rng.gen());
+
+    let entry = LogEntry::new(&request.definition, &resolved, seed, 1);
+
+    if Contest::is_contest(&resolved) {
+      return match Contest::parse_with_bands(&resolved, &self.bands) {
+        Ok(c) => {
+          let result = c.seed(seed).roll();
+          self.record(entry.contest(&result));
+          self.json(200, &result)
+        }
+        Err(err) => self.error(400, &format!("{:?}", err)),
+      };
+    }
+
+    match Roller::parse_with_bands(&resolved, &self.bands) {
+      Ok(r) => {
+        let result = r.seed(seed).roll();
+        self.record(entry.roll(&result));
+        self.json(200, &result)
+      }
+      Err(err) => self.error(400, &format!("{:?}", err)),
+    }
+  }
+
+  /// appends the roll to the log, a log that cannot be written failing no request
+  fn record(&self, entry: LogEntry) {
+    if let Some(log) = &self.log {
+      let _ = log.append(&entry);
+    }
+  }
+
+  /// the exact distribution of a definition
+  fn distribution(&mut self, body: &str) -> ApiResponse {
+    let (_, resolved) = match self.request(body) {
+      Ok(request) => request,
+      Err(response) => return response,
+    };
+
+    let r = match Roller::parse_with_bands(&resolved, &self.bands) {
+      Ok(r) => r,
+      Err(err) => return self.error(400, &format!("{:?}", err)),
+    };
+    if r.pool_size() > MAX_DISTRIBUTION_DICE {
+      return self.error(422, &format!("more than {} dice", MAX_DISTRIBUTION_DICE));
+    }
+    let Some(distribution) = r.distribution() else {
+      return self.error(422, "no exact distribution");
+    };
+
+    let outcomes: Vec<_> = distribution
+      .outcomes()
+      .map(|(outcome, probability)| json!({ "outcome": outcome, "probability": probability }))
+      .collect();
+    self.json(
+      200,
+      &json!({
+        "definition": resolved,
+        "mean": distribution.mean(),
+        "std_dev": distribution.std_dev(),
+        "outcomes": outcomes,
+      }),
+    )
+  }
+
+  /// the request and its definition with macros expanded
+  fn request(&self, body: &str) -> Result<(RollRequest, String), ApiResponse> {
+    let request: RollRequest =
+      serde_json::from_str(body).map_err(|_| self.error(400, "invalid request"))?;
+    let resolved = self
+      .config
+      .expand(&request.definition)
+      .map_err(|err| self.error(400, &format!("{:?}", err)))?;
+    Ok((request, resolved))
+  }
+
+  fn json(&self, status: u16, body: &impl Serialize) -> ApiResponse {
+    match serde_json::to_string(body) {
+      Ok(body) => self.respond(status, body),
+      Err(_) => self.error(500, "serialization failed"),
+    }
+  }
+
+  fn error(&self, status: u16, message: &str) -> ApiResponse {
+    self.respond(status, json!({ "error": message }).to_string())
+  }
+
+  /// the response with its JSON and CORS headers
+  fn respond(&self, status: u16, body: String) -> ApiResponse {
+    let mut headers = vec![(
+      String::from("Content-Type"),
+      String::from("application/json"),
+    )];
+
+    if let Some(origin) = &self.cors {
+      headers.extend([
+        (String::from("Access-Control-Allow-Origin"), origin.clone()),
+        (
+          String::from("Access-Control-Allow-Methods"),
+          String::from("GET, POST, OPTIONS"),
+        ),
+        (
+          String::from("Access-Control-Allow-Headers"),
+          String::from("Content-Type"),
+        ),
+      ]);
+
+      // the answer holds the origin, caches must not serve it to other origins
+      if origin != "*" {
+        headers.push((String::from("Vary"), String::from("Origin")));
+      }
+    }
+
+    ApiResponse {
+      status,
+      headers,
+      body,
+    }
+  }
+}
@@ -0,0 +1,24 @@
+use super::RateLimiter;
+use std::time::Instant;
+
+impl RateLimiter {
+  /// Whether the client may make a request now, taking a token if so
+  pub fn allow(&mut self, client: &str, now: Instant) -> bool {
+    let (tokens, last) = self
+      .buckets
+      .entry(String::from(client))
+      .or_insert((self.burst, now));
+
+    let elapsed = now.saturating_duration_since(*last).as_secs_f64();
+    *tokens = (*tokens + elapsed * self.per_second).min(self.burst);
+    *last = now;
+
+    match *tokens >= 1.0 {
+      true => {
+        *tokens -= 1.0;
+        true
+      }
+      false => false,
+    }
+  }
+}
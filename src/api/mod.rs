@@ -0,0 +1,128 @@
+use super::{Band, Config, RollLog, Tables};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::time::Instant;
+
+// public functions implementation
+mod fn_handle;
+mod fn_rate_limit;
+
+// unit tests
+mod ts_handle;
+mod ts_rate_limit;
+
+#[derive(Debug, PartialEq)]
+/// The answer to an API request, the body being JSON
+pub struct ApiResponse {
+  pub status: u16,
+  pub headers: Vec<(String, String)>,
+  pub body: String,
+}
+
+#[derive(Debug)]
+/// A token bucket per client: every request takes a token, and tokens come back at a steady rate
+/// up to the burst size
+pub struct RateLimiter {
+  /// requests allowed at once
+  burst: f64,
+
+  /// tokens coming back every second
+  per_second: f64,
+
+  /// the tokens of every client and when they were counted
+  buckets: HashMap<String, (f64, Instant)>,
+}
+
+impl RateLimiter {
+  pub fn new(per_second: f64, burst: f64) -> Self {
+    Self {
+      burst,
+      per_second,
+      buckets: HashMap::new(),
+    }
+  }
+}
+
+#[derive(Debug)]
+/// The JSON API behind `iron_dice serve`. Requests are handled one at a time, without any
+/// network access of its own
+///
+/// - POST /roll {"definition": "1d20+5", "seed": 42} throws a definition, or a contest
+/// - POST /distribution {"definition": "4d6 max3"} yields the exact distribution
+/// - GET /tables lists the tables, POST /tables/name rolls on one
+pub struct Api {
+  /// macros and band tables
+  config: Config,
+
+  /// band tables, read from the configuration
+  bands: HashMap<String, Vec<Band>>,
+
+  tables: Tables,
+
+  /// the origin allowed to call the API from a browser, "*" for any
+  cors: Option<String>,
+
+  limiter: Option<RateLimiter>,
+
+  /// the roll log every roll is appended to, if any
+  log: Option<RollLog>,
+
+  /// random number generator, seeding every roller without a seed of its own
+  rng: StdRng,
+}
+
+impl Default for Api {
+  fn default() -> Self {
+    Self {
+      config: Config::default(),
+      bands: HashMap::new(),
+      tables: Tables::new(),
+      cors: None,
+      limiter: None,
+      log: None,
+      rng: StdRng::from_entropy(),
+    }
+  }
+}
+
+impl Api {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the configuration whose macros and band tables definitions may use
+  pub fn config(mut self, config: Config, bands: HashMap<String, Vec<Band>>) -> Self {
+    self.config = config;
+    self.bands = bands;
+    self
+  }
+
+  pub fn tables(mut self, tables: Tables) -> Self {
+    self.tables = tables;
+    self
+  }
+
+  /// Allows browsers on the origin to call the API, "*" for any
+  pub fn cors(mut self, origin: Option<String>) -> Self {
+    self.cors = origin;
+    self
+  }
+
+  pub fn rate_limit(mut self, limiter: Option<RateLimiter>) -> Self {
+    self.limiter = limiter;
+    self
+  }
+
+  /// Appends every roll to the log, along with its seed
+  pub fn log(mut self, log: Option<RollLog>) -> Self {
+    self.log = log;
+    self
+  }
+
+  /// Seeds the random number generator, making rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self
+  }
+}
@@ -0,0 +1,175 @@
+#[test]
+fn roll() {
+  let now = std::time::Instant::now();
+  let mut api = super::Api::new();
+  let response = api.handle(
+    "POST",
+    "/roll",
+    r#"{"definition": "3d6+2", "seed": 7}"#,
+    "a",
+    now,
+  );
+  assert_eq!(response.status, 200);
+
+  let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+  let r: super::super::Roller = String::from("3d6+2").parse().unwrap();
+  let expected = r.seed(7).roll();
+  assert_eq!(body["outcome"], expected.outcome);
+  assert_eq!(body["dice"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn contest_and_macros() {
+  let now = std::time::Instant::now();
+  let config: super::super::Config = String::from("[macros]\nattack = \"1d20+5\"")
+    .parse()
+    .unwrap();
+  let mut api = super::Api::new().config(config, std::collections::HashMap::new());
+
+  let response = api.handle(
+    "POST",
+    "/roll",
+    r#"{"definition": "attack vs 1d20"}"#,
+    "a",
+    now,
+  );
+  let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+  assert_eq!(response.status, 200);
+  assert!(body["winner"].is_string());
+}
+
+#[test]
+fn distribution() {
+  let now = std::time::Instant::now();
+  let mut api = super::Api::new();
+  let response = api.handle(
+    "POST",
+    "/distribution",
+    r#"{"definition": "2d6"}"#,
+    "a",
+    now,
+  );
+  let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+  assert_eq!(body["mean"], 7.0);
+  assert_eq!(body["outcomes"].as_array().unwrap().len(), 11);
+
+  let response = api.handle(
+    "POST",
+    "/distribution",
+    r#"{"definition": "3d6 ex6"}"#,
+    "a",
+    now,
+  );
+  assert_eq!(response.status, 422);
+
+  let response = api.handle(
+    "POST",
+    "/distribution",
+    r#"{"definition": "60d6+41d8"}"#,
+    "a",
+    now,
+  );
+  assert_eq!(response.status, 422);
+}
+
+#[test]
+fn tables() {
+  let now = std::time::Instant::now();
+  let mut tables = super::super::Tables::new();
+  tables.insert(super::super::Table::from_toml("weather", "entries = [\"rain\"]").unwrap());
+  let mut api = super::Api::new().tables(tables);
+
+  let response = api.handle("GET", "/tables", "", "a", now);
+  assert_eq!(response.body, r#"{"tables":["weather"]}"#);
+
+  let response = api.handle("POST", "/tables/weather", "", "a", now);
+  let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+  assert_eq!(body["text"], "rain");
+
+  assert_eq!(
+    api.handle("POST", "/tables/monsters", "", "a", now).status,
+    404
+  );
+}
+
+#[test]
+fn errors() {
+  let now = std::time::Instant::now();
+  let mut api = super::Api::new();
+  assert_eq!(api.handle("POST", "/roll", "3d6", "a", now).status, 400);
+  assert_eq!(
+    api
+      .handle("POST", "/roll", r#"{"definition": "fast"}"#, "a", now)
+      .body,
+    r#"{"error":"Generic"}"#
+  );
+  let response = api.handle("POST", "/roll", r#"{"definition": "1d6 ex1"}"#, "a", now);
+  assert_eq!(response.body, r#"{"error":"EndlessExplosion"}"#);
+  assert_eq!(api.handle("POST", "/nowhere", "", "a", now).status, 404);
+  let response = api.handle("GET", "/roll", "", "a", now);
+  assert_eq!(response.status, 405);
+}
+
+#[test]
+fn cors() {
+  let now = std::time::Instant::now();
+  let mut api = super::Api::new().cors(Some(String::from("https://vtt.example")));
+  let response = api.handle("OPTIONS", "/roll", "", "a", now);
+  assert_eq!(response.status, 204);
+  assert!(response.headers.contains(&(
+    String::from("Access-Control-Allow-Origin"),
+    String::from("https://vtt.example")
+  )));
+  assert!(response
+    .headers
+    .contains(&(String::from("Vary"), String::from("Origin"))));
+
+  let mut api = super::Api::new().cors(Some(String::from("*")));
+  let response = api.handle("OPTIONS", "/roll", "", "a", now);
+  assert!(response.headers.iter().all(|(name, _)| name != "Vary"));
+
+  let mut api = super::Api::new();
+  let response = api.handle("POST", "/roll", r#"{"definition": "1d6"}"#, "a", now);
+  assert!(response
+    .headers
+    .iter()
+    .all(|(name, _)| !name.starts_with("Access-Control")));
+}
+
+#[test]
+fn rate_limit() {
+  let now = std::time::Instant::now();
+  let mut api = super::Api::new().rate_limit(Some(super::RateLimiter::new(1.0, 1.0)));
+  assert_eq!(
+    api
+      .handle("POST", "/roll", r#"{"definition": "1d6"}"#, "a", now)
+      .status,
+    200
+  );
+  assert_eq!(
+    api
+      .handle("POST", "/roll", r#"{"definition": "1d6"}"#, "a", now)
+      .status,
+    429
+  );
+}
+
+#[test]
+fn logged() {
+  let now = std::time::Instant::now();
+  let path = std::env::temp_dir().join(format!("iron_dice_api_log_{}.jsonl", std::process::id()));
+  let mut api = super::Api::new().log(Some(super::super::RollLog::new(&path)));
+  api.handle(
+    "POST",
+    "/roll",
+    r#"{"definition": "2d6", "seed": 3}"#,
+    "a",
+    now,
+  );
+
+  let entries = super::super::RollLog::new(&path).entries().unwrap();
+  std::fs::remove_file(&path).unwrap();
+  assert_eq!(entries.len(), 1);
+  assert_eq!(entries[0].resolved, "2d6");
+  assert_eq!(entries[0].seed, 3);
+}
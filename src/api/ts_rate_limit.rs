@@ -0,0 +1,17 @@
+#[test]
+fn burst_then_refill() {
+  let mut limiter = super::RateLimiter::new(2.0, 3.0);
+  let now = std::time::Instant::now();
+
+  assert!(limiter.allow("a", now));
+  assert!(limiter.allow("a", now));
+  assert!(limiter.allow("a", now));
+  assert!(!limiter.allow("a", now));
+
+  // another client has its own bucket
+  assert!(limiter.allow("b", now));
+
+  let later = now + std::time::Duration::from_millis(500);
+  assert!(limiter.allow("a", later));
+  assert!(!limiter.allow("a", later));
+}
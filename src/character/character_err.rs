@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq)]
+/// Error returned while loading a character sheet or resolving its references
+pub enum CharacterErr {
+  Io,
+  Parse,
+  UnknownReference(String),
+}
@@ -0,0 +1,23 @@
+use super::{Character, CharacterErr};
+use std::fs;
+use std::path::Path;
+
+impl Character {
+  /// Loads a character sheet. Files ending in ".json" are read as JSON, anything else as TOML
+  pub fn load(path: &Path) -> Result<Character, CharacterErr> {
+    let content = fs::read_to_string(path).map_err(|_| CharacterErr::Io)?;
+
+    match path.extension() {
+      Some(extension) if extension == "json" => Character::from_json(&content),
+      _ => Character::from_toml(&content),
+    }
+  }
+
+  pub fn from_toml(content: &str) -> Result<Character, CharacterErr> {
+    toml::from_str(content).map_err(|_| CharacterErr::Parse)
+  }
+
+  pub fn from_json(content: &str) -> Result<Character, CharacterErr> {
+    serde_json::from_str(content).map_err(|_| CharacterErr::Parse)
+  }
+}
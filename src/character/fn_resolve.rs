@@ -0,0 +1,120 @@
+use super::{Character, CharacterErr};
+
+impl Character {
+  /// Replaces every "@name" reference with its value. "1d20+@str_mod+@prof" => "1d20+3+2"
+  pub fn substitute(&self, definition: &str) -> Result<String, CharacterErr> {
+    let mut output = String::new();
+    let mut chars = definition.chars().peekable();
+
+    while let Some(c) = chars.next() {
+      if c != '@' {
+        output.push(c);
+        continue;
+      }
+
+      let mut name = String::new();
+      while let Some(next) = chars.next_if(|x| x.is_alphanumeric() || *x == '_') {
+        name.push(next);
+      }
+
+      let value = self
+        .get(&name)
+        .ok_or(CharacterErr::UnknownReference(name))?;
+      output.push_str(&value.to_string());
+    }
+
+    Ok(output)
+  }
+
+  /// Substitutes the references and sums up the constants of the dice part, so that the result
+  /// can be parsed by `Roller`. "1d20+@str_mod+@prof" => "1d20+5", "@dex+@stealth d10 sc8" => "5d10 sc8"
+  pub fn resolve(&self, definition: &str) -> Result<String, CharacterErr> {
+    let substituted = self.substitute(definition)?;
+    Ok(fold(&substituted))
+  }
+}
+
+/// sums up the constants in the dice part of a definition
+fn fold(definition: &str) -> String {
+  let mut tokens: Vec<String> = definition
+    .split(&[' '])
+    .filter(|x| !x.is_empty())
+    .map(String::from)
+    .collect();
+
+  // pool size written apart from the die: "3+2 d10"
+  if tokens.len() > 1 && is_arithmetic(&tokens[0]) && tokens[1].starts_with('d') {
+    let die = tokens.remove(1);
+    tokens[0].push_str(&die);
+  }
+
+  if let Some(dice) = tokens.first_mut() {
+    *dice = fold_dice(dice);
+  }
+
+  tokens.join(" ")
+}
+
+/// "3+2d10+1+1" => "5d10+2"
+fn fold_dice(token: &str) -> String {
+  let Some((dice, rest)) = token.split_once('d') else {
+    return String::from(token);
+  };
+  let sides_length = rest
+    .find(|x: char| !x.is_ascii_digit())
+    .unwrap_or(rest.len());
+  let (sides, modifier) = rest.split_at(sides_length);
+
+  if !is_arithmetic(dice) || !is_arithmetic(modifier) {
+    return String::from(token);
+  }
+
+  let dice = match dice.is_empty() {
+    true => String::new(),
+    false => sum(dice).to_string(),
+  };
+  let modifier = match (modifier.is_empty(), sum(modifier)) {
+    (true, _) => String::new(),
+    (false, value) if value < 0 => value.to_string(),
+    (false, value) => format!("+{}", value),
+  };
+
+  format!("{}d{}{}", dice, sides, modifier)
+}
+
+fn is_arithmetic(expression: &str) -> bool {
+  expression
+    .chars()
+    .all(|x| x.is_ascii_digit() || x == '+' || x == '-')
+}
+
+/// "+3+2-1" => 4, short of overflowing
+fn sum(expression: &str) -> i32 {
+  let mut total: i32 = 0;
+  let mut sign = 1;
+  let mut value: Option<i32> = None;
+
+  for c in expression.chars() {
+    match c.to_digit(10) {
+      Some(digit) => {
+        value = Some(
+          value
+            .unwrap_or(0)
+            .saturating_mul(10)
+            .saturating_add(digit as i32),
+        )
+      }
+      None => {
+        if let Some(value) = value.take() {
+          total = total.saturating_add(sign * value);
+          sign = 1;
+        }
+        if c == '-' {
+          sign = -sign;
+        }
+      }
+    }
+  }
+
+  total.saturating_add(sign * value.unwrap_or(0))
+}
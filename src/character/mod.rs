@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// public functions implementation
+mod fn_load;
+mod fn_resolve;
+
+// unit tests
+mod ts_resolve;
+
+mod character_err;
+
+pub use character_err::*;
+
+#[derive(Debug, Default, Deserialize)]
+/// A character sheet whose values can be referenced in definitions as "@name"
+pub struct Character {
+  /// character name
+  #[serde(default)]
+  pub name: String,
+
+  /// attributes such as "str_mod" or "dex"
+  #[serde(default)]
+  attributes: HashMap<String, i32>,
+
+  /// skills such as "prof" or "stealth"
+  #[serde(default)]
+  skills: HashMap<String, i32>,
+}
+
+impl Character {
+  /// Returns the value of an attribute or, failing that, of a skill
+  pub fn get(&self, name: &str) -> Option<i32> {
+    self
+      .attributes
+      .get(name)
+      .or_else(|| self.skills.get(name))
+      .copied()
+  }
+}
@@ -0,0 +1,62 @@
+#[test]
+fn load_toml() {
+  let sheet = "name = \"Aria\"\n[attributes]\nstr_mod = 3\n[skills]\nstealth = 2";
+  let c = super::Character::from_toml(sheet).unwrap();
+  assert_eq!(c.name, "Aria");
+  assert_eq!(c.get("str_mod"), Some(3));
+  assert_eq!(c.get("stealth"), Some(2));
+  assert_eq!(c.get("cha_mod"), None);
+}
+
+#[test]
+fn load_json() {
+  let c = super::Character::from_json("{\"attributes\": {\"dex\": 4}}").unwrap();
+  assert_eq!(c.get("dex"), Some(4));
+}
+
+#[test]
+fn substitute() {
+  let c = super::Character::from_toml("[attributes]\nstr_mod = 3\n[skills]\nprof = 2").unwrap();
+  assert_eq!(c.substitute("1d20+@str_mod+@prof").unwrap(), "1d20+3+2");
+}
+
+#[test]
+fn modifier() {
+  let sheet = "[attributes]\nstr_mod = 3\nwis_mod = -1\n[skills]\nprof = 2";
+  let c = super::Character::from_toml(sheet).unwrap();
+  assert_eq!(c.resolve("1d20+@str_mod+@prof").unwrap(), "1d20+5");
+  assert_eq!(c.resolve("d20+@wis_mod max1").unwrap(), "d20-1 max1");
+  assert_eq!(c.resolve("1d20-@str_mod").unwrap(), "1d20-3");
+}
+
+#[test]
+fn pool() {
+  let c = super::Character::from_toml("[attributes]\ndex = 3\n[skills]\nstealth = 2").unwrap();
+  assert_eq!(c.resolve("@dex+@stealth d10 sc8").unwrap(), "5d10 sc8");
+}
+
+#[test]
+fn no_references() {
+  let c = super::Character::default();
+  assert_eq!(c.resolve("3d6 max2").unwrap(), "3d6 max2");
+}
+
+#[test]
+fn huge_numbers() {
+  let c = super::Character::default();
+  assert_eq!(
+    c.resolve("999999999999d6").unwrap(),
+    format!("{}d6", i32::MAX)
+  );
+}
+
+#[test]
+fn unknown_reference() {
+  let c = super::Character::default();
+  assert_eq!(
+    c.resolve("1d20+@str_mod"),
+    Err(super::CharacterErr::UnknownReference(String::from(
+      "str_mod"
+    )))
+  );
+}
@@ -0,0 +1,16 @@
+use super::record;
+use iron_dice::{Blades, LogEntry};
+
+/// Makes a Blades in the Dark action roll. Every roll is logged with its seed
+pub fn blades(dice: u32, seed: Option<u64>, throw_number: u8) {
+  // an unseeded run gets a random seed, so that the log can replay it
+  let seed = seed.unwrap_or_else(rand::random);
+  let command = format!("blades {}", dice);
+
+  let mut r = Blades::new(dice).seed(seed);
+  for throw in 1..=throw_number {
+    let result = r.roll();
+    println!("{}", result);
+    record(LogEntry::new(&command, &command, seed, throw as u32).roll(&result));
+  }
+}
@@ -0,0 +1,123 @@
+use clap::{Args, Subcommand};
+use iron_dice::{Config, Deck, DeckErr};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct DeckArgs {
+  #[command(subcommand)]
+  action: DeckAction,
+
+  #[arg(long)]
+  /// the file keeping the state between runs, default = $XDG_STATE_HOME/iron_dice/deck.json (or bag.json)
+  state: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum DeckAction {
+  /// Starts over, shuffling. A bag needs its tokens: "white:3 black:1"
+  New {
+    tokens: Vec<String>,
+
+    #[arg(long)]
+    /// leaves the jokers out of the deck
+    no_jokers: bool,
+  },
+
+  /// Draws N cards or tokens, default = 1
+  Draw { count: Option<usize> },
+
+  /// Discards the cards in play. Discarding a joker reshuffles the deck
+  Discard,
+
+  /// Shuffles every card back in
+  Shuffle,
+
+  /// Shows the cards in play and how many are left
+  Show,
+}
+
+/// Handles a deck of cards or, when bag is set, a bag of tokens
+pub fn deck(args: DeckArgs, bag: bool, seed: Option<u64>, throw_number: u8) {
+  let name = if bag { "bag" } else { "deck" };
+  let Some(state) = args
+    .state
+    .or(Config::state_dir().map(|x| x.join(format!("{}.json", name))))
+  else {
+    eprintln!("no state directory: set HOME or XDG_STATE_HOME, or use --state");
+    return;
+  };
+
+  let deck = match (&args.action, state.exists(), bag) {
+    (DeckAction::New { tokens, .. }, _, true) => parse_tokens(tokens).map(|x| Deck::bag(&x)),
+    (DeckAction::New { no_jokers, .. }, _, false) => Ok(Deck::standard(!no_jokers)),
+    (_, true, _) => Deck::load(&state),
+    (_, false, false) => Ok(Deck::standard(true)),
+    (_, false, true) => {
+      eprintln!("no bag yet: start the bag with `bag new white:3 black:1`");
+      return;
+    }
+  };
+  let mut deck = match deck {
+    Ok(deck) => deck,
+    Err(err) => {
+      eprintln!("{:?}", err);
+      return;
+    }
+  };
+  if let Some(seed) = seed {
+    deck = deck.seed(seed);
+  }
+
+  // a deck just made is shuffled, whatever the action
+  if matches!(args.action, DeckAction::New { .. }) || !state.exists() {
+    deck.shuffle();
+  }
+
+  match args.action {
+    DeckAction::New { .. } => {}
+    DeckAction::Shuffle => deck.shuffle(),
+    DeckAction::Draw { count } => {
+      for _ in 1..=throw_number {
+        match deck.draw(count.unwrap_or(1)) {
+          Ok(result) => println!("{}", result),
+          Err(err) => {
+            eprintln!("{:?}", err);
+            break;
+          }
+        }
+      }
+    }
+    DeckAction::Discard => {
+      if deck.discard() {
+        println!("joker discarded, {} reshuffled", name);
+      }
+    }
+    DeckAction::Show => {}
+  }
+
+  let drawn: Vec<&str> = deck.drawn().iter().map(|x| x.label.as_str()).collect();
+  println!(
+    "in play [{}], {} left, {} discarded",
+    drawn.join(", "),
+    deck.remaining(),
+    deck.discarded()
+  );
+
+  if let Err(err) = deck.save(&state) {
+    eprintln!("{:?}", err);
+  }
+}
+
+/// "white:3" => ("white", 3), "black" => ("black", 1)
+fn parse_tokens(tokens: &[String]) -> Result<Vec<(String, u32)>, DeckErr> {
+  tokens
+    .iter()
+    .map(|x| match x.split_once(':') {
+      Some((label, count)) => count
+        .parse()
+        .map(|count| (String::from(label), count))
+        .map_err(|_| DeckErr::Parse),
+      None => Ok((x.clone(), 1)),
+    })
+    .collect()
+}
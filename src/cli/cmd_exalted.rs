@@ -0,0 +1,19 @@
+use super::record;
+use iron_dice::{Exalted, LogEntry};
+
+/// Makes an Exalted roll, or a damage roll where 10s count once. Every roll is logged with its seed
+pub fn exalted(dice: u32, damage: bool, seed: Option<u64>, throw_number: u8) {
+  // an unseeded run gets a random seed, so that the log can replay it
+  let seed = seed.unwrap_or_else(rand::random);
+  let command = match damage {
+    true => format!("exalted {} --damage", dice),
+    false => format!("exalted {}", dice),
+  };
+
+  let mut r = Exalted::new(dice).damage(damage).seed(seed);
+  for throw in 1..=throw_number {
+    let result = r.roll();
+    println!("{}", result);
+    record(LogEntry::new(&command, &command, seed, throw as u32).roll(&result));
+  }
+}
@@ -0,0 +1,161 @@
+use super::record;
+use clap::{Args, Subcommand};
+use iron_dice::{fair_seed, verify, Config, FairErr, FairSession, LogEntry};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct FairArgs {
+  #[command(subcommand)]
+  action: FairAction,
+
+  #[arg(long)]
+  /// the file keeping the session between runs, default = $XDG_STATE_HOME/iron_dice/fair.json
+  state: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum FairAction {
+  /// Starts a session with a secret server seed, showing the commitment to publish
+  Commit {
+    #[arg(long)]
+    /// drops the session not revealed yet, whose rolls can then never be verified
+    force: bool,
+  },
+
+  /// Takes the seed chosen by the players once they have the commitment
+  Seed {
+    /// the seed chosen by the players
+    client_seed: String,
+  },
+
+  /// Throws a definition seeded by the next nonce
+  Roll { definition: String },
+
+  /// Shows the server seed, ending the session
+  Reveal,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+  /// the definition thrown
+  definition: String,
+
+  #[arg(long)]
+  /// the commitment published before rolling
+  commitment: String,
+
+  #[arg(long)]
+  /// the server seed revealed after rolling
+  server_seed: String,
+
+  #[arg(long, default_value = "")]
+  /// the seed chosen by the players
+  client_seed: String,
+
+  #[arg(long)]
+  /// the nonce of the roll
+  nonce: u64,
+}
+
+/// Makes provably fair rolls: commit to a secret server seed, take the client seed, roll, then
+/// reveal the server seed
+pub fn fair(args: FairArgs) {
+  let Some(state) = args
+    .state
+    .or(Config::state_dir().map(|x| x.join("fair.json")))
+  else {
+    eprintln!("no state directory: set HOME or XDG_STATE_HOME, or use --state");
+    return;
+  };
+
+  if let FairAction::Commit { force } = args.action {
+    // the server seed of an open session is needed to verify its rolls
+    if state.exists() && !force {
+      eprintln!("a session is still open: `fair reveal` it first, or commit with --force");
+      return;
+    }
+
+    let session = FairSession::random();
+    println!("commitment {}", session.commitment());
+    if let Err(err) = session.save(&state) {
+      eprintln!("{}", err);
+    }
+    return;
+  }
+
+  if !state.exists() {
+    eprintln!("no session yet: start one with `fair commit`");
+    return;
+  }
+  let mut session = match FairSession::load(&state) {
+    Ok(session) => session,
+    Err(err) => {
+      eprintln!("{}", err);
+      return;
+    }
+  };
+
+  match args.action {
+    FairAction::Commit { .. } => (),
+    FairAction::Seed { client_seed } => {
+      match session
+        .accept(&client_seed)
+        .and_then(|_| session.save(&state))
+      {
+        Ok(()) => println!("client seed \"{}\"", client_seed),
+        Err(err) => eprintln!("{}", err),
+      }
+    }
+    FairAction::Roll { definition } => {
+      let (nonce, result) = match session.roll(&definition) {
+        Ok(roll) => roll,
+        Err(err) => {
+          eprintln!("{}", err);
+          return;
+        }
+      };
+      println!("nonce {}: {}", nonce, result);
+      if let Err(err) = session.save(&state) {
+        eprintln!("{}", err);
+      }
+
+      let client_seed = session.client_seed().unwrap_or_default();
+      let seed = fair_seed(session.server_seed(), client_seed, nonce);
+      record(LogEntry::new(&definition, &definition, seed, 1).roll(&result));
+    }
+    FairAction::Reveal => {
+      match session.client_seed() {
+        Some(client_seed) => println!(
+          "server seed {}, client seed \"{}\", {} rolls, commitment {}",
+          session.server_seed(),
+          client_seed,
+          session.nonce(),
+          session.commitment()
+        ),
+        None => println!(
+          "server seed {}, no client seed, commitment {}",
+          session.server_seed(),
+          session.commitment()
+        ),
+      }
+      if fs::remove_file(&state).is_err() {
+        eprintln!("{}", FairErr::Io);
+      }
+    }
+  }
+}
+
+/// Throws a provably fair roll again from the revealed server seed
+pub fn verify_roll(args: VerifyArgs) {
+  match verify(
+    &args.commitment,
+    &args.server_seed,
+    &args.client_seed,
+    args.nonce,
+    &args.definition,
+  ) {
+    Ok(result) => println!("nonce {}: {}", args.nonce, result),
+    Err(err) => eprintln!("{}", err),
+  }
+}
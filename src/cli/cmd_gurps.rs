@@ -0,0 +1,16 @@
+use super::record;
+use iron_dice::{Gurps, LogEntry};
+
+/// Makes a GURPS success roll against the effective skill. Every roll is logged with its seed
+pub fn gurps(skill: i16, seed: Option<u64>, throw_number: u8) {
+  // an unseeded run gets a random seed, so that the log can replay it
+  let seed = seed.unwrap_or_else(rand::random);
+  let command = format!("gurps {}", skill);
+
+  let mut r = Gurps::new(skill).seed(seed);
+  for throw in 1..=throw_number {
+    let result = r.roll();
+    println!("{}", result);
+    record(LogEntry::new(&command, &command, seed, throw as u32).roll(&result));
+  }
+}
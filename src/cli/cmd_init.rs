@@ -0,0 +1,128 @@
+use super::{load_character, load_config};
+use clap::{Parser, Subcommand};
+use iron_dice::{Character, Config, Initiative, InitiativeErr};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(name = "init", args_conflicts_with_subcommands = true)]
+pub struct InitArgs {
+  #[command(subcommand)]
+  action: Option<InitAction>,
+
+  /// starts a new fight, rolling for every combatant. "goblin:1d20+1" "hero:d20+@dex" "villain:cards". None shows the fight going on
+  combatants: Vec<String>,
+
+  #[arg(long)]
+  /// the file keeping the order between runs, default = $XDG_STATE_HOME/iron_dice/initiative.json
+  state: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum InitAction {
+  /// Rolls for a newcomer and puts them in order
+  Add { name: String, definition: String },
+
+  /// Takes a combatant out of the fight
+  Remove { name: String },
+
+  /// Moves a combatant after the next one
+  Delay { name: String },
+
+  /// Passes the turn, starting a new round after the last combatant
+  Next,
+
+  /// Shows the order of play
+  Show,
+}
+
+/// Tracks the initiative order of a fight, kept in a state file between runs. Definitions may
+/// call macros and reference the character sheet
+pub fn initiative(
+  args: InitArgs,
+  config: Option<PathBuf>,
+  character: Option<PathBuf>,
+  seed: Option<u64>,
+) {
+  let (config, _) = load_config(config);
+
+  let character = load_character(character);
+
+  track_fight(args, &config, &character, seed);
+}
+
+/// Tracks the initiative order as the init command, with the configuration and the character
+/// sheet already loaded
+pub(super) fn track_fight(
+  args: InitArgs,
+  config: &Config,
+  character: &Character,
+  seed: Option<u64>,
+) {
+  if let Err(err) = track(args, config, character, seed) {
+    eprintln!("{:?}", err);
+  }
+}
+
+fn track(
+  args: InitArgs,
+  config: &Config,
+  character: &Character,
+  seed: Option<u64>,
+) -> Result<(), InitiativeErr> {
+  let state = args
+    .state
+    .or(Config::state_dir().map(|x| x.join("initiative.json")))
+    .ok_or(InitiativeErr::Io)?;
+
+  // only combatants start a new fight, "init" alone shows the one going on
+  let fresh = args.action.is_none() && !args.combatants.is_empty();
+  let mut initiative = match (fresh, state.exists()) {
+    (true, _) | (false, false) => Initiative::new(),
+    (false, true) => Initiative::load(&state)?,
+  };
+  if let Some(seed) = seed {
+    initiative = initiative.seed(seed);
+  }
+
+  match args.action {
+    None => {
+      for combatant in &args.combatants {
+        let (name, definition) = combatant
+          .split_once(':')
+          .ok_or(InitiativeErr::InvalidDefinition(combatant.clone()))?;
+        initiative.add(name, &resolve(definition, config, character)?)?;
+      }
+    }
+    Some(InitAction::Add { name, definition }) => {
+      initiative.add(&name, &resolve(&definition, config, character)?)?
+    }
+    Some(InitAction::Remove { name }) => {
+      initiative.remove(&name)?;
+    }
+    Some(InitAction::Delay { name }) => initiative.delay(&name)?,
+    Some(InitAction::Next) => {
+      initiative.pass()?;
+    }
+    Some(InitAction::Show) => {}
+  }
+
+  println!("{}", initiative);
+  initiative.save(&state)
+}
+
+/// the definition with macros and character references resolved, "cards" as is
+fn resolve(
+  definition: &str,
+  config: &Config,
+  character: &Character,
+) -> Result<String, InitiativeErr> {
+  if definition == "cards" {
+    return Ok(String::from(definition));
+  }
+
+  config
+    .expand(definition)
+    .ok()
+    .and_then(|x| character.resolve(&x).ok())
+    .ok_or(InitiativeErr::InvalidDefinition(String::from(definition)))
+}
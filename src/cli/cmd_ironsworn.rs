@@ -0,0 +1,43 @@
+use super::record;
+use clap::Subcommand;
+use iron_dice::{Ironsworn, LogEntry};
+
+#[derive(Subcommand)]
+pub enum IronswornArgs {
+  /// Action roll: d6 plus stat and adds against 2d10
+  Action {
+    /// the stat value
+    stat: i16,
+
+    #[arg(long, short, default_value_t = 0, allow_negative_numbers = true)]
+    /// bonuses added to the action die
+    adds: i16,
+  },
+
+  /// Progress roll: the progress score against 2d10
+  Progress {
+    /// the filled progress boxes, 0 to 10
+    score: i16,
+  },
+}
+
+/// Makes an Ironsworn action or progress roll. Every roll is logged with its seed
+pub fn ironsworn(args: IronswornArgs, seed: Option<u64>, throw_number: u8) {
+  let command = match args {
+    IronswornArgs::Action { stat, adds } => format!("ironsworn action {} --adds {}", stat, adds),
+    IronswornArgs::Progress { score } => format!("ironsworn progress {}", score),
+  };
+
+  // an unseeded run gets a random seed, so that the log can replay it
+  let seed = seed.unwrap_or_else(rand::random);
+
+  let mut r = Ironsworn::new().seed(seed);
+  for throw in 1..=throw_number {
+    let result = match args {
+      IronswornArgs::Action { stat, adds } => r.action(stat, adds),
+      IronswornArgs::Progress { score } => r.progress(score),
+    };
+    println!("{}", result);
+    record(LogEntry::new(&command, &command, seed, throw as u32).ironsworn(&result));
+  }
+}
@@ -0,0 +1,160 @@
+use clap::Args;
+use serde_json::{json, Value};
+use std::io::{self, BufRead, ErrorKind};
+use std::process;
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+use std::time::Duration;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Error, Message};
+
+#[derive(Args)]
+pub struct JoinArgs {
+  /// the address of the server started by "serve --rooms". "ws://127.0.0.1:8080"
+  url: String,
+
+  #[arg(long, short)]
+  /// the room to enter, created if needed
+  room: String,
+
+  #[arg(long, short)]
+  /// the name shown to the others
+  name: String,
+
+  #[arg(long)]
+  /// enters as the GM, who may roll in secret
+  gm: bool,
+
+  #[arg(long)]
+  /// the token given to the GM on entering first, taking the GM seat back
+  token: Option<String>,
+}
+
+/// Enters a shared table room: every line read is a definition thrown for the room.
+/// "secret DEF" throws for the GM only, "reveal [id]" shows secret rolls, "quit" leaves
+pub fn join(args: JoinArgs) {
+  let mut socket = match tungstenite::connect(&args.url) {
+    Ok((socket, _)) => socket,
+    Err(err) => {
+      eprintln!("cannot reach {}: {}", args.url, err);
+      process::exit(1);
+    }
+  };
+  if let MaybeTlsStream::Plain(stream) = socket.get_ref() {
+    if let Err(err) = stream.set_read_timeout(Some(Duration::from_millis(20))) {
+      eprintln!("{}", err);
+      process::exit(1);
+    }
+  }
+
+  let join = json!({
+    "type": "join",
+    "room": args.room,
+    "name": args.name,
+    "gm": args.gm,
+    "token": args.token,
+  });
+  if let Err(err) = socket.send(Message::Text(join.to_string())) {
+    eprintln!("{}", err);
+    process::exit(1);
+  }
+
+  let (sender, receiver) = mpsc::channel();
+  thread::spawn(move || {
+    for line in io::stdin().lock().lines().map_while(Result::ok) {
+      if sender.send(line).is_err() {
+        return;
+      }
+    }
+  });
+
+  loop {
+    match socket.read() {
+      Ok(Message::Text(text)) => match serde_json::from_str::<Value>(&text) {
+        Ok(message) => print(&message),
+        Err(err) => eprintln!("{:?}", err),
+      },
+      Ok(Message::Close(_)) => return,
+      Ok(_) => (),
+      // nothing to read yet
+      Err(Error::Io(err)) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+      // the server went away
+      Err(err) => {
+        eprintln!("{}", err);
+        process::exit(1);
+      }
+    }
+
+    let line = match receiver.try_recv() {
+      Ok(line) => line,
+      Err(TryRecvError::Empty) => continue,
+      Err(TryRecvError::Disconnected) => String::from("quit"),
+    };
+    let line = line.trim();
+    let message = match line.split_once(' ').unwrap_or((line, "")) {
+      ("", _) => continue,
+      ("quit", _) => {
+        let _ = socket.send(Message::Text(json!({"type": "leave"}).to_string()));
+        let _ = socket.close(None);
+        return;
+      }
+      ("secret", definition) => json!({"type": "roll", "definition": definition, "secret": true}),
+      ("reveal", "") => json!({"type": "reveal"}),
+      ("reveal", id) => match id.parse::<u64>() {
+        Ok(id) => json!({"type": "reveal", "id": id}),
+        Err(_) => {
+          eprintln!("invalid roll id: {}", id);
+          continue;
+        }
+      },
+      _ => json!({"type": "roll", "definition": line}),
+    };
+    if let Err(err) = socket.send(Message::Text(message.to_string())) {
+      eprintln!("{}", err);
+      process::exit(1);
+    }
+  }
+}
+
+/// prints a message of the server as a line
+fn print(message: &Value) {
+  let text = |key: &str| message[key].as_str().unwrap_or_default().to_string();
+  match message["type"].as_str() {
+    Some("joined") => println!(
+      "{} joined {}: {}",
+      text("name"),
+      text("room"),
+      message["members"]
+        .as_array()
+        .map(|x| x
+          .iter()
+          .filter_map(Value::as_str)
+          .collect::<Vec<_>>()
+          .join(", "))
+        .unwrap_or_default()
+    ),
+    Some("gm_token") => println!(
+      "GM token {}, to take the seat back with --gm --token",
+      text("token")
+    ),
+    Some("left") => println!("{} left", text("name")),
+    Some("roll") => {
+      let tag = match (message["secret"].as_bool(), message["revealed"].as_bool()) {
+        (Some(true), Some(true)) => " (revealed)",
+        (Some(true), _) => " (secret)",
+        _ => "",
+      };
+      println!(
+        "#{} {} rolled {}{}: {}",
+        message["id"],
+        text("name"),
+        text("definition"),
+        tag,
+        text("text")
+      );
+    }
+    Some("secret_roll") => println!("#{} {} rolled in secret", message["id"], text("name")),
+    Some("error") => eprintln!("{}", text("message")),
+    _ => println!("{}", message),
+  }
+}
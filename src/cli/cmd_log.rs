@@ -0,0 +1,124 @@
+use clap::{Args, ValueEnum};
+use iron_dice::{format_timestamp, parse_date, Config, LogEntry, LogFilter, RollLog, RollLogErr};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct LogArgs {
+  /// shows the rolls whose definition or result holds the text. "nat 20" "=> 20"
+  text: Option<String>,
+
+  #[command(flatten)]
+  filter: FilterArgs,
+
+  #[arg(long, short)]
+  /// shows the last N rolls only
+  last: Option<usize>,
+
+  #[arg(long, value_enum)]
+  /// prints the rolls in a format for other tools
+  export: Option<ExportArg>,
+}
+
+#[derive(Args)]
+pub struct FilterArgs {
+  #[arg(long, short)]
+  /// picks the rolls whose definition holds the text. "1d20"
+  definition: Option<String>,
+
+  #[arg(long)]
+  /// picks the rolls made on the date (YYYY-MM-DD) or later
+  since: Option<String>,
+
+  #[arg(long)]
+  /// picks the rolls made on the date (YYYY-MM-DD) or earlier
+  until: Option<String>,
+
+  #[arg(long)]
+  /// the roll log, default = $XDG_STATE_HOME/iron_dice/rolls.jsonl
+  file: Option<PathBuf>,
+}
+
+impl FilterArgs {
+  /// the logged rolls picked, oldest first, along with the text to look for
+  pub(super) fn search(self, text: Option<&str>) -> Result<Vec<LogEntry>, RollLogErr> {
+    let log = roll_log(self.file).ok_or(RollLogErr::Io)?;
+
+    let mut filter = LogFilter::new();
+    if let Some(definition) = &self.definition {
+      filter = filter.definition(definition);
+    }
+    if let Some(text) = text {
+      filter = filter.text(text);
+    }
+    if let Some(since) = &self.since {
+      filter = filter.since(parse_date(since)?);
+    }
+    if let Some(until) = &self.until {
+      // the whole day is included
+      filter = filter.until(parse_date(until)? + 86_400);
+    }
+
+    // a line cut short, by a crash or a full disk, loses that roll only
+    for line in log.corrupt_lines()? {
+      eprintln!("line {} of the roll log skipped: no roll", line);
+    }
+
+    log.search(&filter)
+  }
+}
+
+#[derive(Clone, ValueEnum)]
+enum ExportArg {
+  /// one JSON object per line, as logged
+  Jsonl,
+
+  /// comma separated values, with a header
+  Csv,
+}
+
+/// The roll log, default = $XDG_STATE_HOME/iron_dice/rolls.jsonl
+pub(super) fn roll_log(file: Option<PathBuf>) -> Option<RollLog> {
+  let path = file.or(Config::state_dir().map(|x| x.join("rolls.jsonl")))?;
+  Some(RollLog::new(&path))
+}
+
+/// Appends a roll to the log. A log that cannot be written is reported without stopping the roll
+pub fn record(entry: LogEntry) {
+  if let Some(Err(err)) = roll_log(None).map(|x| x.append(&entry)) {
+    eprintln!("{:?}", err);
+  }
+}
+
+/// Searches the roll log. Every roll shows the seed and the throw replaying it:
+/// `iron_dice --seed S -t T -d "resolved"` shows it as its last throw
+pub fn log(args: LogArgs) {
+  let entries = match args.filter.search(args.text.as_deref()) {
+    Ok(entries) => entries,
+    Err(err) => {
+      eprintln!("{:?}", err);
+      return;
+    }
+  };
+  let skipped = entries
+    .len()
+    .saturating_sub(args.last.unwrap_or(entries.len()));
+
+  if let Some(ExportArg::Csv) = args.export {
+    println!("{}", LogEntry::csv_header());
+  }
+
+  for entry in entries.iter().skip(skipped) {
+    match args.export {
+      Some(ExportArg::Jsonl) => println!("{}", serde_json::to_string(entry).unwrap()),
+      Some(ExportArg::Csv) => println!("{}", entry.to_csv()),
+      None => println!(
+        "{} {} => {} (seed {}, throw {})",
+        format_timestamp(entry.timestamp),
+        entry.definition,
+        entry.result,
+        entry.seed,
+        entry.throw
+      ),
+    }
+  }
+}
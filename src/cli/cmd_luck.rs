@@ -0,0 +1,25 @@
+use super::{load_config, FilterArgs};
+use clap::Args;
+use iron_dice::LuckReport;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct LuckArgs {
+  #[command(flatten)]
+  filter: FilterArgs,
+}
+
+/// Compares the logged rolls to the exact distribution of their definitions, then the logged
+/// dice to fair ones
+pub fn luck(args: LuckArgs, config: Option<PathBuf>) {
+  let (_, bands) = load_config(config);
+  let entries = match args.filter.search(None) {
+    Ok(entries) => entries,
+    Err(err) => {
+      eprintln!("{:?}", err);
+      return;
+    }
+  };
+
+  println!("{}", LuckReport::new(&entries, &bands));
+}
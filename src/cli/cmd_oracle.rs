@@ -0,0 +1,74 @@
+use clap::Subcommand;
+use iron_dice::{Likelihood, Oracle, Tables};
+
+#[derive(Subcommand)]
+pub enum OracleArgs {
+  /// Asks a yes/no question
+  Ask {
+    /// how likely a yes is: almost-certain, likely, 50/50, unlikely, small-chance
+    likelihood: String,
+
+    #[arg(long, default_value_t = 5)]
+    /// Mythic chaos factor, 1 to 9
+    chaos: u8,
+  },
+
+  /// Rolls on the built-in oracle tables: action, theme, descriptor, focus
+  Roll {
+    /// the tables to roll on, "action theme"
+    tables: Vec<String>,
+  },
+}
+
+/// Asks the yes/no oracle or rolls on the built-in oracle tables
+pub fn oracle(args: OracleArgs, seed: Option<u64>, throw_number: u8) {
+  match args {
+    OracleArgs::Ask { likelihood, chaos } => {
+      let likelihood: Likelihood = match likelihood.parse() {
+        Ok(likelihood) => likelihood,
+        Err(()) => {
+          eprintln!(
+            "unknown likelihood \"{}\": almost-certain, likely, 50/50, unlikely, small-chance",
+            likelihood
+          );
+          return;
+        }
+      };
+      let mut oracle = Oracle::new().chaos(chaos);
+      if let Some(seed) = seed {
+        oracle = oracle.seed(seed);
+      }
+
+      for _ in 1..=throw_number {
+        println!("{}", oracle.ask(likelihood));
+      }
+    }
+    OracleArgs::Roll { tables: names } => {
+      let mut tables = Tables::new();
+      if let Some(seed) = seed {
+        tables = tables.seed(seed);
+      }
+      match Oracle::tables() {
+        Ok(oracle) => oracle.into_iter().for_each(|x| tables.insert(x)),
+        Err(err) => {
+          eprintln!("{:?}", err);
+          return;
+        }
+      }
+
+      for _ in 1..=throw_number {
+        let results: Result<Vec<String>, _> = names
+          .iter()
+          .map(|x| tables.roll(x).map(|x| x.text))
+          .collect();
+        match results {
+          Ok(results) => println!("{}", results.join(" / ")),
+          Err(err) => {
+            eprintln!("{:?}", err);
+            return;
+          }
+        }
+      }
+    }
+  }
+}
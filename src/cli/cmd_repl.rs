@@ -0,0 +1,117 @@
+use super::{load_character, load_config, record, track_fight, InitArgs};
+use clap::Parser;
+use iron_dice::{Character, Config, Contest, LogEntry, RollResult, Roller};
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// Throws the definitions read line by line from the standard input, resolving macros and
+/// character references first. "push" pushes the last throw, "init ..." tracks initiative,
+/// "quit" leaves
+pub fn repl(config: Option<PathBuf>, character: Option<PathBuf>, seed: Option<u64>) {
+  let (config, _) = load_config(config);
+
+  let character = load_character(character);
+
+  let mut last: Option<(Roller, RollResult, LogEntry)> = None;
+  let mut throws: u64 = 0;
+
+  // an unseeded session gets a random seed, so that the log can replay it
+  let seed = seed.unwrap_or_else(rand::random);
+
+  prompt();
+  for line in io::stdin().lock().lines() {
+    let line = line.unwrap();
+    let line = line.trim();
+
+    match line {
+      "" => (),
+      "quit" | "exit" => break,
+      "push" => match &mut last {
+        Some((r, result, entry)) => {
+          *result = r.push(result);
+          println!("{}", result);
+
+          // a pushed throw is no plain one, kept apart from them in the log
+          entry.throw += 1;
+          let pushed = format!("{} push", entry.resolved);
+          record(LogEntry::new("push", &pushed, entry.seed, entry.throw).roll(result));
+        }
+        None => println!("nothing to push yet"),
+      },
+      init if init.split_whitespace().next() == Some("init") => {
+        match InitArgs::try_parse_from(init.split_whitespace()) {
+          Ok(args) => {
+            // seeded by the session as throws are
+            let seed = seed.wrapping_add(throws);
+            throws += 1;
+            track_fight(args, &config, &character, Some(seed));
+          }
+          Err(err) => println!("{}", err),
+        }
+      }
+      definition if Contest::is_contest(definition) => {
+        match oppose(definition, &config, &character) {
+          Ok((resolved, c)) => {
+            let seed = seed.wrapping_add(throws);
+            throws += 1;
+
+            let result = c.seed(seed).roll();
+            println!("{}", result);
+            record(LogEntry::new(definition, &resolved, seed, 1).contest(&result));
+          }
+          Err(err) => println!("{}", err),
+        }
+      }
+      definition => match throw(definition, &config, &character) {
+        Ok((resolved, r)) => {
+          let seed = seed.wrapping_add(throws);
+          throws += 1;
+
+          let mut r = r.seed(seed);
+          let result = r.roll();
+          println!("{}", result);
+          let entry = LogEntry::new(definition, &resolved, seed, 1).roll(&result);
+          record(entry.clone());
+          last = Some((r, result, entry));
+        }
+        Err(err) => println!("{}", err),
+      },
+    }
+
+    prompt();
+  }
+}
+
+/// the resolved definition and its roller, with macros and character references resolved
+fn throw(
+  definition: &str,
+  config: &Config,
+  character: &Character,
+) -> Result<(String, Roller), String> {
+  let resolved = resolve(definition, config, character)?;
+  let bands = config.bands().map_err(|x| format!("{:?}", x))?;
+  let r = Roller::parse_with_bands(&resolved, &bands).map_err(|x| format!("{:?}", x))?;
+  Ok((resolved, r))
+}
+
+/// the resolved definition and its contest, with macros and character references resolved
+fn oppose(
+  definition: &str,
+  config: &Config,
+  character: &Character,
+) -> Result<(String, Contest), String> {
+  let resolved = resolve(definition, config, character)?;
+  let bands = config.bands().map_err(|x| format!("{:?}", x))?;
+  let c = Contest::parse_with_bands(&resolved, &bands).map_err(|x| format!("{:?}", x))?;
+  Ok((resolved, c))
+}
+
+fn resolve(definition: &str, config: &Config, character: &Character) -> Result<String, String> {
+  let expanded = config.expand(definition).map_err(|x| format!("{:?}", x))?;
+  character.resolve(&expanded).map_err(|x| format!("{:?}", x))
+}
+
+fn prompt() {
+  print!("> ");
+  io::stdout().flush().unwrap();
+}
@@ -0,0 +1,140 @@
+use super::record;
+use iron_dice::{Band, Character, Config, Contest, LogEntry, Roller};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process;
+
+/// Throws every definition, resolving macros and character references first. Every throw is
+/// logged with its seed
+pub fn roll(
+  definition: Option<Vec<String>>,
+  config: Option<PathBuf>,
+  character: Option<PathBuf>,
+  explain: bool,
+  seed: Option<u64>,
+  throw_number: u8,
+) {
+  let definition = match definition {
+    Some(definition) => definition,
+    None => vec![String::from("3d6")],
+  };
+
+  let (config, bands) = load_config(config);
+
+  let character = load_character(character);
+
+  let mut failed = false;
+  for (index, definition) in definition.into_iter().enumerate() {
+    // every definition gets a seed of its own, a random one in an unseeded run so that the log
+    // can replay it
+    let seed = match seed {
+      Some(seed) => seed.wrapping_add(index as u64),
+      None => rand::random(),
+    };
+
+    let expanded = match config.expand(&definition) {
+      Ok(expanded) => expanded,
+      Err(err) => {
+        eprintln!("{:?}", err);
+        failed = true;
+        continue;
+      }
+    };
+    let resolved = character
+      .substitute(&expanded)
+      .and_then(|substituted| Ok((substituted, character.resolve(&expanded)?)));
+    let (substituted, resolved) = match resolved {
+      Ok(resolved) => resolved,
+      Err(err) => {
+        eprintln!("{:?}", err);
+        failed = true;
+        continue;
+      }
+    };
+
+    if explain {
+      let mut steps = vec![definition.clone(), expanded, substituted, resolved.clone()];
+      steps.extend(Roller::roll_and_keep(&resolved));
+      steps.dedup();
+      println!("{}", steps.join(" => "));
+    }
+
+    if Contest::is_contest(&resolved) {
+      contest(&definition, &resolved, &bands, seed, throw_number);
+      continue;
+    }
+
+    let mut r = match Roller::parse_with_bands(&resolved, &bands) {
+      Ok(r) => r.seed(seed),
+      Err(err) => {
+        eprintln!("{:?}", err);
+        failed = true;
+        continue;
+      }
+    };
+
+    for throw in 1..=throw_number {
+      let result = r.roll();
+      println!("{}", result);
+      record(LogEntry::new(&definition, &resolved, seed, throw as u32).roll(&result));
+    }
+  }
+
+  if failed {
+    process::exit(1);
+  }
+}
+
+/// The configuration and its band tables, ending the run with the error when it is malformed
+pub(super) fn load_config(path: Option<PathBuf>) -> (Config, HashMap<String, Vec<Band>>) {
+  let loaded = Config::load(path).and_then(|config| {
+    let bands = config.bands()?;
+    Ok((config, bands))
+  });
+
+  match loaded {
+    Ok(loaded) => loaded,
+    Err(err) => {
+      eprintln!("{:?}", err);
+      process::exit(1);
+    }
+  }
+}
+
+/// The character sheet, empty without a path, ending the run with the error when it is malformed
+pub(super) fn load_character(path: Option<PathBuf>) -> Character {
+  let Some(path) = path else {
+    return Character::default();
+  };
+
+  match Character::load(&path) {
+    Ok(character) => character,
+    Err(err) => {
+      eprintln!("{:?}", err);
+      process::exit(1);
+    }
+  }
+}
+
+/// Throws an opposed roll, "1d20+5 vs 1d20+3"
+fn contest(
+  definition: &str,
+  resolved: &str,
+  bands: &HashMap<String, Vec<Band>>,
+  seed: u64,
+  throw_number: u8,
+) {
+  let mut c = match Contest::parse_with_bands(resolved, bands) {
+    Ok(c) => c.seed(seed),
+    Err(err) => {
+      eprintln!("{:?}", err);
+      return;
+    }
+  };
+
+  for throw in 1..=throw_number {
+    let result = c.roll();
+    println!("{}", result);
+    record(LogEntry::new(definition, resolved, seed, throw as u32).contest(&result));
+  }
+}
@@ -0,0 +1,45 @@
+use clap::Args;
+use iron_dice::SelfTest;
+use std::process;
+
+#[derive(Args)]
+pub struct SelfTestArgs {
+  /// the definitions to test, default = a built-in set covering every modifier
+  definitions: Vec<String>,
+
+  #[arg(long, short)]
+  /// the throws per definition, default = 100000
+  rolls: Option<u64>,
+}
+
+/// Throws definitions many times and tests them against their exact distribution and fair dice.
+/// Exits with an error when a test fails
+pub fn selftest(args: SelfTestArgs, seed: Option<u64>) {
+  let mut test = SelfTest::new();
+  if !args.definitions.is_empty() {
+    test = test.definitions(args.definitions);
+  }
+  if let Some(rolls) = args.rolls {
+    test = test.rolls(rolls);
+  }
+  if let Some(seed) = seed {
+    test = test.seed(seed);
+  }
+
+  let cases = match test.run() {
+    Ok(cases) => cases,
+    Err(err) => {
+      eprintln!("{:?}", err);
+      process::exit(1);
+    }
+  };
+  for case in &cases {
+    println!("{}", case);
+  }
+
+  let passed = cases.iter().filter(|x| x.passed()).count();
+  println!("{} of {} passed", passed, cases.len());
+  if passed < cases.len() {
+    process::exit(1);
+  }
+}
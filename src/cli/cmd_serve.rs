@@ -0,0 +1,113 @@
+use super::{load_config, load_tables, roll_log};
+use clap::Args;
+use iron_dice::{serve_rooms, Api, RateLimiter, RoomPolicy, Rooms};
+use std::io::Read;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::time::Instant;
+use tiny_http::{Header, Response, Server};
+
+/// largest request body read, in bytes, the rest being left out
+const MAX_BODY: u64 = 64 * 1024;
+
+#[derive(Args)]
+pub struct ServeArgs {
+  #[arg(long, short, default_value_t = 8080)]
+  /// the port to listen on
+  port: u16,
+
+  #[arg(long, default_value = "127.0.0.1")]
+  /// the address to listen on, local only by default
+  host: String,
+
+  #[arg(long)]
+  /// the origin allowed to call the API from a browser, "*" for any. No CORS headers otherwise
+  cors: Option<String>,
+
+  #[arg(long, default_value_t = 10.0)]
+  /// requests per second allowed to every client, 0 for no limit
+  rate: f64,
+
+  #[arg(long, default_value_t = 20.0)]
+  /// requests a client may make at once
+  burst: f64,
+
+  #[arg(long)]
+  /// the directory holding the tables, default = $XDG_CONFIG_HOME/iron_dice/tables
+  tables: Option<PathBuf>,
+
+  #[arg(long)]
+  /// serves shared table rooms over WebSocket instead of the JSON API
+  rooms: bool,
+}
+
+/// Serves the JSON API over HTTP until stopped
+pub fn serve(args: ServeArgs, config: Option<PathBuf>, seed: Option<u64>) {
+  let (config, bands) = load_config(config);
+  let address = format!("{}:{}", args.host, args.port);
+
+  if args.rooms {
+    let mut rooms = Rooms::new().config(config, bands).log(roll_log(None));
+    if let Some(seed) = seed {
+      rooms = rooms.seed(seed);
+    }
+    let policy = RoomPolicy {
+      origin: args.cors,
+      rate: (args.rate > 0.0).then_some((args.rate, args.burst.max(1.0))),
+    };
+
+    let listener = TcpListener::bind(&address).unwrap();
+    println!("listening on ws://{}", address);
+    serve_rooms(listener, rooms, policy);
+    return;
+  }
+
+  let limiter = match args.rate > 0.0 {
+    true => Some(RateLimiter::new(args.rate, args.burst.max(1.0))),
+    false => None,
+  };
+
+  let mut api = Api::new()
+    .config(config, bands)
+    .tables(load_tables(args.tables))
+    .cors(args.cors)
+    .rate_limit(limiter)
+    .log(roll_log(None));
+  if let Some(seed) = seed {
+    api = api.seed(seed);
+  }
+
+  let server = Server::http(&address).unwrap();
+  println!("listening on http://{}", address);
+
+  for mut request in server.incoming_requests() {
+    let mut body = String::new();
+    let mut reader = request.as_reader().take(MAX_BODY);
+    if reader.read_to_string(&mut body).is_err() {
+      body.clear();
+    }
+
+    let client = request
+      .remote_addr()
+      .map(|x| x.ip().to_string())
+      .unwrap_or_default();
+    let answer = api.handle(
+      request.method().as_str(),
+      request.url(),
+      &body,
+      &client,
+      Instant::now(),
+    );
+
+    let mut response = Response::from_string(answer.body).with_status_code(answer.status);
+    for (name, value) in answer.headers {
+      if let Ok(header) = Header::from_bytes(name.as_bytes(), value.as_bytes()) {
+        response.add_header(header);
+      }
+    }
+
+    if let Err(err) = request.respond(response) {
+      eprintln!("{:?}", err);
+    }
+  }
+}
@@ -0,0 +1,47 @@
+use super::record;
+use clap::{Args, ValueEnum};
+use iron_dice::{Edge, LogEntry, Shadowrun};
+
+#[derive(Args)]
+pub struct ShadowrunArgs {
+  /// the number of dice in the pool
+  dice: u32,
+
+  #[arg(long, short, value_enum)]
+  /// spends edge on the test
+  edge: Option<EdgeArg>,
+}
+
+#[derive(Clone, ValueEnum)]
+enum EdgeArg {
+  /// 6s explode
+  Six,
+
+  /// dice without a hit are rerolled once
+  Reroll,
+}
+
+/// Makes a Shadowrun test. Every roll is logged with its seed
+pub fn shadowrun(args: ShadowrunArgs, seed: Option<u64>, throw_number: u8) {
+  let (edge, command) = match args.edge {
+    None => (None, format!("shadowrun {}", args.dice)),
+    Some(EdgeArg::Six) => (
+      Some(Edge::RuleOfSix),
+      format!("shadowrun {} --edge six", args.dice),
+    ),
+    Some(EdgeArg::Reroll) => (
+      Some(Edge::SecondChance),
+      format!("shadowrun {} --edge reroll", args.dice),
+    ),
+  };
+
+  // an unseeded run gets a random seed, so that the log can replay it
+  let seed = seed.unwrap_or_else(rand::random);
+
+  let mut r = Shadowrun::new(args.dice).edge(edge).seed(seed);
+  for throw in 1..=throw_number {
+    let result = r.roll();
+    println!("{}", result);
+    record(LogEntry::new(&command, &command, seed, throw as u32).roll(&result));
+  }
+}
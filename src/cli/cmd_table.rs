@@ -0,0 +1,123 @@
+use clap::Args;
+use iron_dice::{Config, Oracle, Table, Tables};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct TableArgs {
+  /// the table name, a built-in oracle table (action, theme, descriptor, focus), or the path of a table file (TOML, CSV or Markdown)
+  table: String,
+
+  #[arg(long)]
+  /// the directory holding the tables, default = $XDG_CONFIG_HOME/iron_dice/tables
+  tables: Option<PathBuf>,
+
+  #[arg(long)]
+  /// draws N distinct entries instead of rolling
+  draw: Option<usize>,
+
+  #[arg(long)]
+  /// remembers the drawn entries between runs, so that they are never drawn again
+  unique: bool,
+
+  #[arg(long)]
+  /// makes every entry drawable again
+  reset: bool,
+}
+
+/// Rolls on a table, given either its name or the path of its file
+pub fn table(args: TableArgs, seed: Option<u64>, throw_number: u8) {
+  let mut tables = load_tables(args.tables);
+  if let Some(seed) = seed {
+    tables = tables.seed(seed);
+  }
+
+  let path = Path::new(&args.table);
+  let name = match path.is_file() {
+    false => args.table.clone(),
+    true => match Table::load(path) {
+      Ok(table) => {
+        let name = table.name.clone();
+        tables.insert(table);
+        name
+      }
+      Err(err) => {
+        eprintln!("{}: {:?}", path.display(), err);
+        return;
+      }
+    },
+  };
+
+  let exhausted = Config::state_dir().map(|x| x.join("exhausted.json"));
+  let exhausted = match (args.unique || args.reset, exhausted) {
+    (false, _) => None,
+    (true, Some(exhausted)) => Some(exhausted),
+    (true, None) => {
+      eprintln!("no state directory: set HOME or XDG_STATE_HOME");
+      return;
+    }
+  };
+  // loaded whenever it is saved, keeping the draws of the other tables
+  if let Some(exhausted) = &exhausted {
+    if let Err(err) = tables.load_exhausted(exhausted) {
+      eprintln!("{:?}", err);
+      return;
+    }
+  }
+
+  if args.reset {
+    tables.reset(&name);
+  }
+
+  for _ in 1..=throw_number {
+    let results = match (args.draw, args.unique) {
+      (Some(count), _) => tables.draw(&name, count),
+      (None, true) => tables.draw(&name, 1),
+      (None, false) if args.reset => Ok(Vec::new()),
+      (None, false) => tables.roll(&name).map(|x| vec![x]),
+    };
+
+    match results {
+      Ok(results) => {
+        results
+          .into_iter()
+          .for_each(|result| match args.draw.is_some() || args.unique {
+            // draws pick an entry left rather than rolling
+            true => println!("{} => {}", result.roll.outcome, result.text),
+            false => println!("{} => {}", result.roll, result.text),
+          })
+      }
+      Err(err) => {
+        eprintln!("{:?}", err);
+        break;
+      }
+    }
+  }
+
+  if let Some(exhausted) = exhausted {
+    if let Err(err) = tables.save_exhausted(&exhausted) {
+      eprintln!("{:?}", err);
+    }
+  }
+}
+
+/// The built-in oracle tables and those of the directory, default = $XDG_CONFIG_HOME/iron_dice/tables
+pub(super) fn load_tables(directory: Option<PathBuf>) -> Tables {
+  let mut tables = Tables::new();
+  Oracle::tables()
+    .unwrap()
+    .into_iter()
+    .for_each(|x| tables.insert(x));
+
+  let directory = directory.or(Config::default_dir().map(|x| x.join("tables")));
+  if let Some(directory) = directory.filter(|x| x.is_dir()) {
+    // a bad file is skipped, the other tables remain usable
+    match tables.load_dir(&directory) {
+      Ok(skipped) => skipped
+        .iter()
+        .for_each(|(path, err)| eprintln!("{}: {:?}", path.display(), err)),
+      Err(err) => eprintln!("{}: {:?}", directory.display(), err),
+    }
+  }
+
+  tables
+}
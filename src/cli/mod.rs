@@ -0,0 +1,36 @@
+// subcommands implementation
+mod cmd_blades;
+mod cmd_deck;
+mod cmd_exalted;
+mod cmd_fair;
+mod cmd_gurps;
+mod cmd_init;
+mod cmd_ironsworn;
+mod cmd_join;
+mod cmd_log;
+mod cmd_luck;
+mod cmd_oracle;
+mod cmd_repl;
+mod cmd_roll;
+mod cmd_selftest;
+mod cmd_serve;
+mod cmd_shadowrun;
+mod cmd_table;
+
+pub use cmd_blades::*;
+pub use cmd_deck::*;
+pub use cmd_exalted::*;
+pub use cmd_fair::*;
+pub use cmd_gurps::*;
+pub use cmd_init::*;
+pub use cmd_ironsworn::*;
+pub use cmd_join::*;
+pub use cmd_log::*;
+pub use cmd_luck::*;
+pub use cmd_oracle::*;
+pub use cmd_repl::*;
+pub use cmd_roll::*;
+pub use cmd_selftest::*;
+pub use cmd_serve::*;
+pub use cmd_shadowrun::*;
+pub use cmd_table::*;
@@ -0,0 +1,8 @@
+#[derive(Debug, PartialEq)]
+/// Error returned while loading the configuration or expanding a macro
+pub enum ConfigErr {
+  Io,
+  Parse,
+  Arguments,
+  Recursion,
+}
@@ -0,0 +1,20 @@
+use super::super::Band;
+use super::{Config, ConfigErr};
+use std::collections::HashMap;
+
+impl Config {
+  /// The band tables defined in the configuration
+  pub fn bands(&self) -> Result<HashMap<String, Vec<Band>>, ConfigErr> {
+    self
+      .bands
+      .iter()
+      .map(|(name, bands)| {
+        let bands = bands
+          .iter()
+          .map(|x| x.parse::<Band>().map_err(|_| ConfigErr::Parse))
+          .collect::<Result<Vec<Band>, ConfigErr>>()?;
+        Ok((name.clone(), bands))
+      })
+      .collect()
+  }
+}
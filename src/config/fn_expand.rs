@@ -0,0 +1,72 @@
+use super::{Config, ConfigErr};
+
+/// maximum nesting of macros calling other macros
+const MAX_DEPTH: u8 = 16;
+
+impl Config {
+  /// Replaces every macro call in the definition with the macro body. "greatsword", "attack(5) ex20"
+  pub fn expand(&self, definition: &str) -> Result<String, ConfigErr> {
+    self.expand_nested(definition, 0)
+  }
+
+  fn expand_nested(&self, definition: &str, depth: u8) -> Result<String, ConfigErr> {
+    if depth > MAX_DEPTH {
+      return Err(ConfigErr::Recursion);
+    }
+
+    let mut tokens: Vec<String> = Vec::new();
+    for token in definition.split(&[' ']).filter(|x| !x.is_empty()) {
+      match self.find_macro(token)? {
+        None => tokens.push(String::from(token)),
+        Some(body) => tokens.push(self.expand_nested(&body, depth + 1)?),
+      }
+    }
+
+    Ok(tokens.join(" "))
+  }
+
+  /// returns the body of the macro called by the token, with its parameters substituted
+  fn find_macro(&self, token: &str) -> Result<Option<String>, ConfigErr> {
+    let (name, arguments) = split_call(token);
+    let mut found = false;
+
+    for (key, body) in &self.macros {
+      let (key_name, parameters) = split_call(key);
+      if key_name != name {
+        continue;
+      }
+      found = true;
+      if parameters.len() != arguments.len() {
+        continue;
+      }
+
+      let mut body = body.clone();
+      parameters.iter().zip(arguments.iter()).for_each(|(p, a)| {
+        body = body.replace(&format!("{{{}}}", p), a);
+      });
+      return Ok(Some(body));
+    }
+
+    if found {
+      return Err(ConfigErr::Arguments);
+    }
+
+    Ok(None)
+  }
+}
+
+/// splits "name(a,b)" into ("name", ["a", "b"])
+fn split_call(token: &str) -> (&str, Vec<&str>) {
+  match token.split_once('(') {
+    Some((name, rest)) if rest.ends_with(')') => {
+      let arguments = rest
+        .trim_end_matches(')')
+        .split(&[','])
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .collect();
+      (name, arguments)
+    }
+    _ => (token, Vec::new()),
+  }
+}
@@ -0,0 +1,50 @@
+use super::{Config, ConfigErr};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+impl Config {
+  /// Loads the configuration from the given path. Without a path the default location is used,
+  /// and a missing default file yields an empty configuration
+  pub fn load(path: Option<PathBuf>) -> Result<Config, ConfigErr> {
+    match path {
+      Some(path) => Config::load_file(&path),
+      None => match Config::default_path() {
+        Some(path) if path.exists() => Config::load_file(&path),
+        _ => Ok(Config::default()),
+      },
+    }
+  }
+
+  /// `$XDG_CONFIG_HOME/iron_dice/config.toml`, falling back to `$HOME/.config`
+  pub fn default_path() -> Option<PathBuf> {
+    Some(Config::default_dir()?.join("config.toml"))
+  }
+
+  /// `$XDG_CONFIG_HOME/iron_dice`, falling back to `$HOME/.config`
+  pub fn default_dir() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_CONFIG_HOME") {
+      Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+      _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(base.join("iron_dice"))
+  }
+
+  /// `$XDG_STATE_HOME/iron_dice`, falling back to `$HOME/.local/state`. Holds data kept between runs
+  pub fn state_dir() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_STATE_HOME") {
+      Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+      _ => PathBuf::from(env::var_os("HOME")?)
+        .join(".local")
+        .join("state"),
+    };
+
+    Some(base.join("iron_dice"))
+  }
+
+  fn load_file(path: &Path) -> Result<Config, ConfigErr> {
+    let content = fs::read_to_string(path).map_err(|_| ConfigErr::Io)?;
+    content.parse()
+  }
+}
@@ -0,0 +1,11 @@
+use super::{Config, ConfigErr};
+use std::str::FromStr;
+
+/// for idiomatic parsing
+impl FromStr for Config {
+  type Err = ConfigErr;
+
+  fn from_str(content: &str) -> Result<Config, ConfigErr> {
+    toml::from_str(content).map_err(|_| ConfigErr::Parse)
+  }
+}
@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// public functions implementation
+mod fn_bands;
+mod fn_expand;
+mod fn_load;
+mod impl_from_str;
+
+// unit tests
+mod ts_expand;
+
+mod config_err;
+
+pub use config_err::*;
+
+#[derive(Debug, Default, Deserialize)]
+/// User configuration. Usually read from `$XDG_CONFIG_HOME/iron_dice/config.toml`
+pub struct Config {
+  /// named definitions. A name may declare parameters, such as "attack(bonus)"
+  #[serde(default)]
+  macros: HashMap<String, String>,
+
+  /// named band tables, such as "pbta" = ["10+: strong hit", "7-9: weak hit", "6-: miss"]
+  #[serde(default)]
+  bands: HashMap<String, Vec<String>>,
+}
@@ -0,0 +1,84 @@
+#[test]
+fn parse() {
+  let c: super::Config = String::from("[macros]\ngreatsword = \"2d6+5\"")
+    .parse()
+    .unwrap();
+  assert_eq!(c.macros.get("greatsword"), Some(&String::from("2d6+5")));
+}
+
+#[test]
+fn parse_error() {
+  let c: Result<super::Config, super::ConfigErr> = String::from("[macros").parse();
+  assert_eq!(c.unwrap_err(), super::ConfigErr::Parse);
+}
+
+#[test]
+fn plain_definition() {
+  let c: super::Config = String::from("").parse().unwrap();
+  assert_eq!(c.expand("3d6 max2").unwrap(), "3d6 max2");
+}
+
+#[test]
+fn simple_macro() {
+  let c: super::Config = String::from("[macros]\ngreatsword = \"2d6+5\"")
+    .parse()
+    .unwrap();
+  assert_eq!(c.expand("greatsword").unwrap(), "2d6+5");
+}
+
+#[test]
+fn macro_with_parameters() {
+  let c: super::Config = String::from("[macros]\n\"attack(bonus)\" = \"1d20+{bonus}\"")
+    .parse()
+    .unwrap();
+  assert_eq!(c.expand("attack(5)").unwrap(), "1d20+5");
+}
+
+#[test]
+fn macro_with_many_parameters() {
+  let c: super::Config = String::from("[macros]\n\"pool(n,t)\" = \"{n}d10 sc{t}\"")
+    .parse()
+    .unwrap();
+  assert_eq!(c.expand("pool(7,8) ex10").unwrap(), "7d10 sc8 ex10");
+}
+
+#[test]
+fn nested_macros() {
+  let c: super::Config = String::from(
+    "[macros]\n\"attack(bonus)\" = \"1d20+{bonus}\"\nsword = \"attack(5)\"\nsword_adv = \"sword max1\"",
+  )
+  .parse()
+  .unwrap();
+  assert_eq!(c.expand("sword_adv").unwrap(), "1d20+5 max1");
+}
+
+#[test]
+fn wrong_arguments() {
+  let c: super::Config = String::from("[macros]\n\"attack(bonus)\" = \"1d20+{bonus}\"")
+    .parse()
+    .unwrap();
+  assert_eq!(c.expand("attack").unwrap_err(), super::ConfigErr::Arguments);
+}
+
+#[test]
+fn recursion() {
+  let c: super::Config = String::from("[macros]\nloop = \"loop\"").parse().unwrap();
+  assert_eq!(c.expand("loop").unwrap_err(), super::ConfigErr::Recursion);
+}
+
+#[test]
+fn bands() {
+  let c: super::Config = String::from("[bands]\nfate = [\"3+: success with style\", \"0-2: tie\"]")
+    .parse()
+    .unwrap();
+  let bands = c.bands().unwrap();
+  assert_eq!(
+    bands.get("fate").unwrap()[0],
+    super::super::Band::new(3, i16::MAX, "success with style")
+  );
+
+  let c: super::Config = String::from("[bands]\nbroken = [\"three: oops\"]")
+    .parse()
+    .unwrap();
+  assert_eq!(c.bands().unwrap_err(), super::ConfigErr::Parse);
+}
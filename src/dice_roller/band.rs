@@ -0,0 +1,75 @@
+use super::RollerErr;
+use std::str::FromStr;
+
+/// An outcome range mapped to a named result, such as 4-5 "partial"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Band {
+  pub low: i16,
+  pub high: i16,
+  pub label: String,
+
+  /// how many dice must show the outcome for the band to apply, such as two sixes for a critical
+  pub matching: usize,
+}
+
+impl Band {
+  pub fn new(low: i16, high: i16, label: &str) -> Self {
+    Self {
+      low,
+      high,
+      label: String::from(label),
+      matching: 0,
+    }
+  }
+
+  pub fn matching(mut self, matching: usize) -> Self {
+    self.matching = matching;
+    self
+  }
+
+  /// Whether the band applies to the outcome of the dice
+  pub fn contains(&self, dice: &[u16], outcome: i16) -> bool {
+    let matching = dice.iter().filter(|x| **x as i16 == outcome).count();
+    self.low <= outcome && outcome <= self.high && matching >= self.matching
+  }
+
+  /// Built-in band tables: "pbta" is 10+ strong hit, 7-9 weak hit and 6- miss
+  pub fn preset(name: &str) -> Option<Vec<Band>> {
+    match name {
+      "pbta" => Some(vec![
+        Band::new(10, i16::MAX, "strong hit"),
+        Band::new(7, 9, "weak hit"),
+        Band::new(i16::MIN, 6, "miss"),
+      ]),
+      _ => None,
+    }
+  }
+}
+
+/// "10+: strong hit", "7-9: weak hit", "6-: miss", "12: boxcars"
+impl FromStr for Band {
+  type Err = RollerErr;
+
+  fn from_str(descriptor: &str) -> Result<Band, RollerErr> {
+    let (range, label) = descriptor.split_once(':').ok_or(RollerErr::Generic)?;
+    let range = range.trim();
+
+    let (low, high) = if let Some(low) = range.strip_suffix('+') {
+      (parse_bound(low)?, i16::MAX)
+    } else if let Some(high) = range.strip_suffix('-') {
+      (i16::MIN, parse_bound(high)?)
+    } else if let Some(split) = range.get(1..).and_then(|x| x.find('-')) {
+      // a negative low bound keeps its sign
+      let (low, high) = range.split_at(split + 1);
+      (parse_bound(low)?, parse_bound(&high[1..])?)
+    } else {
+      (parse_bound(range)?, parse_bound(range)?)
+    };
+
+    Ok(Band::new(low, high, label.trim()))
+  }
+}
+
+fn parse_bound(bound: &str) -> Result<i16, RollerErr> {
+  bound.trim().parse().map_err(|_| RollerErr::Generic)
+}
@@ -0,0 +1,28 @@
+use super::super::{ContestResult, Winner};
+use super::{Contest, TieBreak, MAX_REROLLS};
+
+impl Contest {
+  /// Rolls both sides. The higher outcome wins, ties are broken by the tie break policy
+  pub fn roll(&mut self) -> ContestResult {
+    let mut rerolls = 0;
+    loop {
+      let attacker = self.attacker.roll();
+      let defender = self.defender.roll();
+      let margin = attacker.outcome - defender.outcome;
+
+      let winner = match (margin, self.tie_break) {
+        (1.., _) => Winner::Attacker,
+        (..=-1, _) => Winner::Defender,
+        (_, TieBreak::Attacker) => Winner::Attacker,
+        (_, TieBreak::Defender) => Winner::Defender,
+        (_, TieBreak::Reroll) if rerolls < MAX_REROLLS => {
+          rerolls += 1;
+          continue;
+        }
+        (_, TieBreak::Tie | TieBreak::Reroll) => Winner::Tie,
+      };
+
+      return ContestResult::new(attacker, defender, winner);
+    }
+  }
+}
@@ -0,0 +1,53 @@
+use super::super::Band;
+use super::{Contest, Roller, RollerErr, TieBreak};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// "1d20+5 vs 1d20+3", "6d6 sc5 vs 4d6 sc5 tie:defender"
+impl FromStr for Contest {
+  type Err = RollerErr;
+
+  fn from_str(descriptor: &str) -> Result<Contest, RollerErr> {
+    Contest::parse_with_bands(descriptor, &HashMap::new())
+  }
+}
+
+impl Contest {
+  /// Parses the contest, looking up "bands:name" of either side among the given band tables
+  /// before the built-in ones
+  pub fn parse_with_bands(
+    descriptor: &str,
+    bands: &HashMap<String, Vec<Band>>,
+  ) -> Result<Contest, RollerErr> {
+    let mut tie_break = TieBreak::Tie;
+    let mut tokens: Vec<&str> = Vec::new();
+    for token in descriptor.split(' ') {
+      match token.strip_prefix("tie:") {
+        Some(policy) => tie_break = Contest::parse_tie_break(policy)?,
+        None => tokens.push(token),
+      }
+    }
+
+    let descriptor = tokens.join(" ");
+    let (attacker, defender) = descriptor.split_once(" vs ").ok_or(RollerErr::Generic)?;
+    let attacker = Roller::parse_with_bands(attacker.trim(), bands)?;
+    let defender = Roller::parse_with_bands(defender.trim(), bands)?;
+
+    Ok(Contest::new(attacker, defender).tie_break(tie_break))
+  }
+
+  /// Whether the definition opposes two rolls
+  pub fn is_contest(descriptor: &str) -> bool {
+    descriptor.contains(" vs ")
+  }
+
+  fn parse_tie_break(policy: &str) -> Result<TieBreak, RollerErr> {
+    match policy {
+      "tie" => Ok(TieBreak::Tie),
+      "attacker" => Ok(TieBreak::Attacker),
+      "defender" => Ok(TieBreak::Defender),
+      "reroll" => Ok(TieBreak::Reroll),
+      _ => Err(RollerErr::Generic),
+    }
+  }
+}
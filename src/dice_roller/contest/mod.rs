@@ -0,0 +1,55 @@
+use super::{Roller, RollerErr};
+
+// public functions implementation
+mod fn_roll;
+mod impl_from_str;
+
+// unit tests
+mod ts_roll;
+
+/// rerolls allowed before a tie stands
+const MAX_REROLLS: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Who wins a contest ending in a tie
+pub enum TieBreak {
+  /// nobody, the tie stands
+  Tie,
+  Attacker,
+  Defender,
+
+  /// both roll again until someone wins
+  Reroll,
+}
+
+#[derive(Debug)]
+/// An opposed roll: "1d20+5 vs 1d20+3", or pool against pool comparing net successes
+pub struct Contest {
+  attacker: Roller,
+
+  defender: Roller,
+
+  tie_break: TieBreak,
+}
+
+impl Contest {
+  pub fn new(attacker: Roller, defender: Roller) -> Self {
+    Self {
+      attacker,
+      defender,
+      tie_break: TieBreak::Tie,
+    }
+  }
+
+  pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+    self.tie_break = tie_break;
+    self
+  }
+
+  /// Seeds the random number generators, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.attacker = self.attacker.seed(seed);
+    self.defender = self.defender.seed(seed.wrapping_add(1));
+    self
+  }
+}
@@ -0,0 +1,74 @@
+#[test]
+fn parse() {
+  let c: super::Contest = "1d20+5 vs 1d20+3".parse().unwrap();
+  assert_eq!(c.attacker, "1d20+5".parse().unwrap());
+  assert_eq!(c.defender, "1d20+3".parse().unwrap());
+  assert_eq!(c.tie_break, super::TieBreak::Tie);
+
+  let c: super::Contest = "6d6 sc5 vs 4d6 sc5 tie:defender".parse().unwrap();
+  assert_eq!(c.defender, "4d6 sc5".parse().unwrap());
+  assert_eq!(c.tie_break, super::TieBreak::Defender);
+
+  let c: Result<super::Contest, super::RollerErr> = "1d20+5".parse();
+  assert_eq!(c.unwrap_err(), super::RollerErr::Generic);
+  let c: Result<super::Contest, super::RollerErr> = "1d20 vs 1d20 tie:coin".parse();
+  assert_eq!(c.unwrap_err(), super::RollerErr::Generic);
+}
+
+#[test]
+fn parse_with_bands() {
+  let mut bands = std::collections::HashMap::new();
+  bands.insert(
+    String::from("duel"),
+    vec![super::super::Band::new(1, 20, "hit")],
+  );
+  let c = super::Contest::parse_with_bands("1d20 bands:duel vs 1d20 bands:duel", &bands).unwrap();
+  let side = super::Roller::parse_with_bands("1d20 bands:duel", &bands).unwrap();
+  assert_eq!(c.attacker, side);
+  assert_eq!(c.defender, side);
+
+  let c: Result<super::Contest, super::RollerErr> = "1d20 bands:duel vs 1d20".parse();
+  assert_eq!(
+    c.unwrap_err(),
+    super::RollerErr::UnknownBands(String::from("duel"))
+  );
+}
+
+#[test]
+fn roll() {
+  let mut c: super::Contest = "1d20+5 vs 1d20+3".parse().unwrap();
+  for _ in 1..=1000 {
+    let result = c.roll();
+    let margin = result.attacker.outcome - result.defender.outcome;
+    assert_eq!(result.margin, margin.abs());
+    let winner = match margin {
+      1.. => super::super::Winner::Attacker,
+      0 => super::super::Winner::Tie,
+      _ => super::super::Winner::Defender,
+    };
+    assert_eq!(result.winner, winner);
+  }
+}
+
+#[test]
+fn net_successes() {
+  let mut c: super::Contest = "6d6 sc5 vs 4d6 sc5 tie:defender".parse().unwrap();
+  for _ in 1..=1000 {
+    let result = c.roll();
+    let winner = match result.attacker.outcome > result.defender.outcome {
+      true => super::super::Winner::Attacker,
+      false => super::super::Winner::Defender,
+    };
+    assert_eq!(result.winner, winner);
+  }
+}
+
+#[test]
+fn reroll_ties() {
+  let mut c: super::Contest = "1d2 vs 1d2 tie:reroll".parse().unwrap();
+  for _ in 1..=1000 {
+    let result = c.roll();
+    assert_ne!(result.winner, super::super::Winner::Tie);
+    assert!(result.margin > 0);
+  }
+}
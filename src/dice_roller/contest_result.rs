@@ -0,0 +1,62 @@
+use super::RollResult;
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+/// The side winning a contest
+pub enum Winner {
+  Attacker,
+  Defender,
+  Tie,
+}
+
+impl fmt::Display for Winner {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Winner::Attacker => write!(f, "attacker"),
+      Winner::Defender => write!(f, "defender"),
+      Winner::Tie => write!(f, "tie"),
+    }
+  }
+}
+
+/// A struct holding the result of an opposed roll
+#[derive(Debug, Serialize)]
+pub struct ContestResult {
+  pub attacker: RollResult,
+  pub defender: RollResult,
+  pub winner: Winner,
+
+  /// how much the winner's outcome beat the loser's, 0 for a tie
+  pub margin: i16,
+}
+
+impl ContestResult {
+  pub fn new(attacker: RollResult, defender: RollResult, winner: Winner) -> Self {
+    let margin = (attacker.outcome - defender.outcome).abs();
+
+    Self {
+      attacker,
+      defender,
+      winner,
+      margin,
+    }
+  }
+}
+
+/// "[14] => 19 vs [8] => 11 => attacker by 8", "[3] => 3 vs [3] => 3 => tie"
+impl fmt::Display for ContestResult {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{} vs {} => {}",
+      self.attacker, self.defender, self.winner
+    )?;
+
+    match self.margin {
+      0 => Ok(()),
+      margin => write!(f, " by {}", margin),
+    }
+  }
+}
@@ -0,0 +1,85 @@
+use super::RollFlag;
+
+/// A skill-dependent table of critical results for roll-under checks
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Criticals {
+  /// (lowest skill, highest roll): from that skill on, rolls up to the value are critical successes
+  pub success: Vec<(i16, i16)>,
+
+  /// (highest skill, lowest roll): up to that skill, rolls from the value on are critical failures
+  pub failure: Vec<(i16, i16)>,
+
+  /// rolls missing the skill by this much or more are critical failures
+  pub failure_margin: Option<i16>,
+
+  /// rolls up to this value succeed, whatever the skill
+  pub automatic_success: Option<i16>,
+
+  /// rolls from this value on fail, whatever the skill
+  pub automatic_failure: Option<i16>,
+}
+
+impl Criticals {
+  /// 3-4 are critical successes, 5 at skill 15 and 6 at skill 16. 18 is a critical failure, 17
+  /// up to skill 15, and so is missing by 10 or more. 3-4 always succeed and 17-18 always fail
+  pub fn gurps() -> Self {
+    Self {
+      success: vec![(i16::MIN, 4), (15, 5), (16, 6)],
+      failure: vec![(i16::MAX, 18), (15, 17)],
+      failure_margin: Some(10),
+      automatic_success: Some(4),
+      automatic_failure: Some(17),
+    }
+  }
+
+  /// 1 is a critical success and 100 a fumble, 96-100 under skill 50
+  pub fn brp() -> Self {
+    Self {
+      success: vec![(i16::MIN, 1)],
+      failure: vec![(i16::MAX, 100), (49, 96)],
+      failure_margin: None,
+      automatic_success: None,
+      automatic_failure: None,
+    }
+  }
+
+  /// Built-in tables by name: "gurps", "brp"
+  pub fn preset(name: &str) -> Option<Self> {
+    match name {
+      "gurps" => Some(Criticals::gurps()),
+      "brp" => Some(Criticals::brp()),
+      _ => None,
+    }
+  }
+
+  /// Whether the roll is a critical success or failure at the skill
+  pub fn check(&self, skill: i16, roll: i16) -> Option<RollFlag> {
+    let success = self
+      .success
+      .iter()
+      .any(|(lowest_skill, highest_roll)| skill >= *lowest_skill && roll <= *highest_roll);
+    let failure = self
+      .failure
+      .iter()
+      .any(|(highest_skill, lowest_roll)| skill <= *highest_skill && roll >= *lowest_roll);
+    let missed_by = self
+      .failure_margin
+      .is_some_and(|margin| roll - skill >= margin);
+
+    match (success, failure || missed_by) {
+      (true, _) => Some(RollFlag::CriticalSuccess),
+      (false, true) => Some(RollFlag::CriticalFailure),
+      (false, false) => None,
+    }
+  }
+
+  /// Whether the roll succeeds (true) or fails (false) whatever the skill. None when the skill
+  /// decides
+  pub fn automatic(&self, roll: i16) -> Option<bool> {
+    match (self.automatic_success, self.automatic_failure) {
+      (Some(highest), _) if roll <= highest => Some(true),
+      (_, Some(lowest)) if roll >= lowest => Some(false),
+      _ => None,
+    }
+  }
+}
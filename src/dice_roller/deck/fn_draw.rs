@@ -0,0 +1,48 @@
+use super::super::{DeckErr, DrawResult};
+use super::Deck;
+use rand::seq::SliceRandom;
+
+impl Deck {
+  /// Shuffles the cards in play and the discarded ones back into the pile
+  pub fn shuffle(&mut self) {
+    self.pile.append(&mut self.drawn);
+    self.pile.append(&mut self.discard);
+    self.pile.shuffle(&mut self.rng);
+  }
+
+  /// Draws cards from the top of the pile. When the pile runs out, the discarded cards are
+  /// shuffled back into it
+  pub fn draw(&mut self, count: usize) -> Result<DrawResult, DeckErr> {
+    if count > self.pile.len() + self.discard.len() {
+      return Err(DeckErr::Empty);
+    }
+
+    let mut cards = Vec::new();
+    for _ in 1..=count {
+      if self.pile.is_empty() {
+        self.pile.append(&mut self.discard);
+        self.pile.shuffle(&mut self.rng);
+      }
+
+      if let Some(card) = self.pile.pop() {
+        self.drawn.push(card.clone());
+        cards.push(card);
+      }
+    }
+
+    Ok(DrawResult::new(cards))
+  }
+
+  /// Moves the cards in play to the discard pile. Returns whether a joker among them caused the
+  /// whole deck to be reshuffled
+  pub fn discard(&mut self) -> bool {
+    let reshuffle = self.reshuffle_on_joker && self.drawn.iter().any(|x| x.joker);
+
+    self.discard.append(&mut self.drawn);
+    if reshuffle {
+      self.shuffle();
+    }
+
+    reshuffle
+  }
+}
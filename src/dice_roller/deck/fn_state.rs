@@ -0,0 +1,22 @@
+use super::super::DeckErr;
+use super::Deck;
+use std::fs;
+use std::path::Path;
+
+impl Deck {
+  /// Restores a deck saved by a previous run
+  pub fn load(path: &Path) -> Result<Deck, DeckErr> {
+    let content = fs::read_to_string(path).map_err(|_| DeckErr::Io)?;
+    serde_json::from_str(&content).map_err(|_| DeckErr::Parse)
+  }
+
+  /// Saves the pile, the cards in play and the discarded ones
+  pub fn save(&self, path: &Path) -> Result<(), DeckErr> {
+    if let Some(directory) = path.parent() {
+      fs::create_dir_all(directory).map_err(|_| DeckErr::Io)?;
+    }
+
+    let content = serde_json::to_string(self).map_err(|_| DeckErr::Parse)?;
+    fs::write(path, content).map_err(|_| DeckErr::Io)
+  }
+}
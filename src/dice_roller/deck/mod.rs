@@ -0,0 +1,126 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+// public functions implementation
+mod fn_draw;
+mod fn_state;
+
+// unit tests
+mod ts_draw;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A card of a deck or a token of a bag
+pub struct Card {
+  pub label: String,
+
+  /// used to compare cards, 0 for tokens
+  pub rank: u8,
+
+  pub joker: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// A deck of cards or a bag of tokens. Drawn cards stay in play until discarded
+pub struct Deck {
+  /// cards left to draw, the top one last
+  pile: Vec<Card>,
+
+  /// cards in play
+  drawn: Vec<Card>,
+
+  /// discarded cards
+  discard: Vec<Card>,
+
+  /// whether discarding a joker shuffles every card back into the pile
+  reshuffle_on_joker: bool,
+
+  /// random number generator
+  #[serde(skip, default = "StdRng::from_entropy")]
+  rng: StdRng,
+}
+
+/// card values, lowest first
+const VALUES: [&str; 13] = [
+  "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A",
+];
+
+/// suits in Savage Worlds order, lowest first
+const SUITS: [&str; 4] = ["♣", "♦", "♥", "♠"];
+
+impl Deck {
+  /// A 52 cards deck, plus two jokers if required, ranked by value then suit as in Savage Worlds.
+  /// Discarding a joker reshuffles the deck. Cards are in order until shuffled
+  pub fn standard(jokers: bool) -> Self {
+    let mut pile: Vec<Card> = Vec::new();
+
+    for (v, value) in VALUES.iter().enumerate() {
+      for (s, suit) in SUITS.iter().enumerate() {
+        pile.push(Card {
+          label: format!("{}{}", value, suit),
+          rank: (v * SUITS.len() + s + 1) as u8,
+          joker: false,
+        });
+      }
+    }
+
+    if jokers {
+      for (j, label) in ["Black Joker", "Red Joker"].iter().enumerate() {
+        pile.push(Card {
+          label: String::from(*label),
+          rank: (VALUES.len() * SUITS.len() + j + 1) as u8,
+          joker: true,
+        });
+      }
+    }
+
+    Deck::new(pile, jokers)
+  }
+
+  /// A bag holding the given number of tokens per label. Tokens are in order until shuffled
+  pub fn bag(tokens: &[(String, u32)]) -> Self {
+    let pile = tokens
+      .iter()
+      .flat_map(|(label, count)| {
+        (0..*count).map(|_| Card {
+          label: label.clone(),
+          rank: 0,
+          joker: false,
+        })
+      })
+      .collect();
+
+    Deck::new(pile, false)
+  }
+
+  fn new(pile: Vec<Card>, reshuffle_on_joker: bool) -> Self {
+    Self {
+      pile,
+      drawn: Vec::new(),
+      discard: Vec::new(),
+      reshuffle_on_joker,
+      rng: StdRng::from_entropy(),
+    }
+  }
+
+  /// Seeds the random number generator, making shuffles reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self
+  }
+
+  /// Number of cards left to draw
+  pub fn remaining(&self) -> usize {
+    self.pile.len()
+  }
+
+  /// Cards in play
+  pub fn drawn(&self) -> &[Card] {
+    &self.drawn
+  }
+
+  /// Number of discarded cards
+  pub fn discarded(&self) -> usize {
+    self.discard.len()
+  }
+}
@@ -0,0 +1,119 @@
+#[test]
+fn standard() {
+  let d = super::Deck::standard(true);
+  assert_eq!(d.remaining(), 54);
+  let d = super::Deck::standard(false);
+  assert_eq!(d.remaining(), 52);
+}
+
+#[test]
+fn ranks() {
+  let mut d = super::Deck::standard(true);
+  let result = d.draw(54).unwrap();
+  let mut ranks: Vec<u8> = result.cards.iter().map(|x| x.rank).collect();
+  ranks.sort();
+  assert_eq!(ranks, (1..=54).collect::<Vec<u8>>());
+  assert_eq!(result.best().unwrap().label, "Red Joker");
+}
+
+#[test]
+fn draw() {
+  let mut d = super::Deck::standard(false);
+  d.shuffle();
+  let result = d.draw(5).unwrap();
+  assert_eq!(result.cards.len(), 5);
+  assert_eq!(d.remaining(), 47);
+  assert_eq!(d.drawn().len(), 5);
+}
+
+#[test]
+fn distinct_cards() {
+  let mut d = super::Deck::standard(true);
+  d.shuffle();
+  let mut labels: Vec<String> = d
+    .draw(54)
+    .unwrap()
+    .cards
+    .into_iter()
+    .map(|x| x.label)
+    .collect();
+  labels.sort();
+  labels.dedup();
+  assert_eq!(labels.len(), 54);
+  assert_eq!(d.draw(1).unwrap_err(), super::super::DeckErr::Empty);
+}
+
+#[test]
+fn discard() {
+  let mut d = super::Deck::standard(false);
+  d.shuffle();
+  d.draw(5).unwrap();
+  assert!(!d.discard());
+  assert_eq!(d.drawn().len(), 0);
+  assert_eq!(d.discarded(), 5);
+}
+
+#[test]
+fn reshuffle_from_discard() {
+  let mut d = super::Deck::standard(false);
+  d.draw(50).unwrap();
+  d.discard();
+  let result = d.draw(5).unwrap();
+  assert_eq!(result.cards.len(), 5);
+  assert_eq!(d.discarded(), 0);
+}
+
+#[test]
+fn reshuffle_on_joker() {
+  let mut d = super::Deck::standard(true);
+  let result = d.draw(1).unwrap();
+  assert!(result.cards[0].joker);
+  assert!(d.discard());
+  assert_eq!(d.remaining(), 54);
+  assert_eq!(d.discarded(), 0);
+}
+
+#[test]
+fn bag() {
+  let mut b = super::Deck::bag(&[(String::from("white"), 3), (String::from("black"), 1)]);
+  b.shuffle();
+  assert_eq!(b.remaining(), 4);
+  let result = b.draw(4).unwrap();
+  let blacks = result.cards.iter().filter(|x| x.label == "black").count();
+  assert_eq!(blacks, 1);
+  assert!(!b.discard());
+}
+
+#[test]
+fn display() {
+  let mut d = super::Deck::standard(false);
+  let result = d.draw(2).unwrap();
+  assert_eq!(result.to_string(), "[A♠, A♥] => A♠");
+
+  let mut b = super::Deck::bag(&[(String::from("white"), 2)]);
+  let result = b.draw(2).unwrap();
+  assert_eq!(result.to_string(), "[white, white]");
+}
+
+#[test]
+fn seeded() {
+  let mut d1 = super::Deck::standard(true).seed(3);
+  let mut d2 = super::Deck::standard(true).seed(3);
+  d1.shuffle();
+  d2.shuffle();
+  assert_eq!(d1.draw(10).unwrap().cards, d2.draw(10).unwrap().cards);
+}
+
+#[test]
+fn save_and_load() {
+  let path = std::env::temp_dir().join(format!("iron_dice_deck_{}.json", std::process::id()));
+  let mut d = super::Deck::standard(true);
+  d.shuffle();
+  let drawn = d.draw(3).unwrap().cards;
+  d.save(&path).unwrap();
+
+  let restored = super::Deck::load(&path).unwrap();
+  std::fs::remove_file(&path).unwrap();
+  assert_eq!(restored.remaining(), 51);
+  assert_eq!(restored.drawn(), drawn.as_slice());
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq)]
+/// Error returned while drawing from a deck or saving its state
+pub enum DeckErr {
+  Empty,
+  Io,
+  Parse,
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The exact probability of every outcome of a roll
+pub struct Distribution {
+  outcomes: BTreeMap<i16, f64>,
+}
+
+impl Distribution {
+  /// A sure outcome
+  pub fn constant(outcome: i16) -> Self {
+    Self {
+      outcomes: BTreeMap::from([(outcome, 1.0)]),
+    }
+  }
+
+  /// Every face of a die as likely as the others
+  pub fn uniform(sides: u32) -> Self {
+    let probability = 1.0 / sides as f64;
+    Self {
+      outcomes: (1..=sides).map(|x| (x as i16, probability)).collect(),
+    }
+  }
+
+  /// Outcomes counted over every possible roll, each as likely as the others
+  pub fn from_counts(counts: &BTreeMap<i16, u64>) -> Self {
+    let total: u64 = counts.values().sum();
+    Self {
+      outcomes: counts
+        .iter()
+        .map(|(outcome, count)| (*outcome, *count as f64 / total as f64))
+        .collect(),
+    }
+  }
+
+  /// The distribution of the sum of two independent outcomes
+  pub fn add(&self, other: &Distribution) -> Self {
+    let mut outcomes: BTreeMap<i16, f64> = BTreeMap::new();
+    for (a, p) in &self.outcomes {
+      for (b, q) in &other.outcomes {
+        *outcomes.entry(a.saturating_add(*b)).or_default() += p * q;
+      }
+    }
+    Self { outcomes }
+  }
+
+  /// The distribution moved by a constant
+  pub fn shift(&self, offset: i16) -> Self {
+    Self {
+      outcomes: self
+        .outcomes
+        .iter()
+        .map(|(x, p)| (x.saturating_add(offset), *p))
+        .collect(),
+    }
+  }
+
+  /// Every outcome with its probability, lowest first
+  pub fn outcomes(&self) -> impl Iterator<Item = (i16, f64)> + '_ {
+    self.outcomes.iter().map(|(x, p)| (*x, *p))
+  }
+
+  pub fn probability(&self, outcome: i16) -> f64 {
+    self.outcomes.get(&outcome).copied().unwrap_or(0.0)
+  }
+
+  /// The probability of an outcome at or under the given one
+  pub fn at_most(&self, outcome: i16) -> f64 {
+    self.outcomes.range(..=outcome).map(|(_, p)| p).sum()
+  }
+
+  /// Where the outcome stands, from 0 to 1: the chance of rolling lower, plus half the chance of
+  /// rolling it. 0.5 is as lucky as can be expected
+  pub fn percentile(&self, outcome: i16) -> f64 {
+    self.at_most(outcome) - self.probability(outcome) / 2.0
+  }
+
+  pub fn mean(&self) -> f64 {
+    self.outcomes.iter().map(|(x, p)| *x as f64 * p).sum()
+  }
+
+  pub fn variance(&self) -> f64 {
+    let mean = self.mean();
+    self
+      .outcomes
+      .iter()
+      .map(|(x, p)| (*x as f64 - mean).powi(2) * p)
+      .sum()
+  }
+
+  pub fn std_dev(&self) -> f64 {
+    self.variance().sqrt()
+  }
+}
@@ -0,0 +1,32 @@
+use super::Card;
+use std::fmt;
+
+/// A struct holding the cards or tokens drawn
+#[derive(Debug)]
+pub struct DrawResult {
+  pub cards: Vec<Card>,
+}
+
+impl DrawResult {
+  pub fn new(cards: Vec<Card>) -> Self {
+    Self { cards }
+  }
+
+  /// The highest ranked card, such as the one acting first in Savage Worlds initiative
+  pub fn best(&self) -> Option<&Card> {
+    self.cards.iter().max_by_key(|x| x.rank)
+  }
+}
+
+/// "[K♠, 3♥] => K♠". Tokens from a bag are not ranked, so only the list is shown
+impl fmt::Display for DrawResult {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let labels: Vec<&str> = self.cards.iter().map(|x| x.label.as_str()).collect();
+    write!(f, "[{}]", labels.join(", "))?;
+
+    match self.best() {
+      Some(best) if best.rank > 0 => write!(f, " => {}", best.label),
+      _ => Ok(()),
+    }
+  }
+}
@@ -0,0 +1,43 @@
+/// How a Cortex pool is split into a total and an effect die. Dice showing 1 are hitches and
+/// count for neither
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EffectStrategy {
+  /// the two highest dice make the total, the largest of the rest is the effect die
+  BestTotal,
+
+  /// the largest die is the effect die, the two highest of the rest make the total
+  BestEffect,
+}
+
+impl EffectStrategy {
+  /// Picks the total and the effect die size. Without dice left the effect die is a d4
+  pub fn select(&self, dice: &[u16], sides: &[u32]) -> (i16, u32) {
+    let mut available: Vec<(u16, u32)> = dice
+      .iter()
+      .zip(sides.iter())
+      .filter(|(x, _)| **x != 1)
+      .map(|(x, y)| (*x, *y))
+      .collect();
+
+    let effect = match self {
+      EffectStrategy::BestTotal => {
+        // equal results keep the smaller die in the total, saving the larger for the effect
+        available.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        available.iter().skip(2).map(|x| x.1).max()
+      }
+      EffectStrategy::BestEffect => {
+        // among the largest dice the lowest result goes to the effect
+        available.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let effect = match available.is_empty() {
+          true => None,
+          false => Some(available.remove(0).1),
+        };
+        available.sort_by_key(|x| std::cmp::Reverse(x.0));
+        effect
+      }
+    };
+
+    let total = available.iter().take(2).map(|x| x.0 as i16).sum();
+    (total, effect.unwrap_or(4))
+  }
+}
@@ -0,0 +1,20 @@
+use super::super::IronswornResult;
+use super::{Ironsworn, MAX_SCORE};
+
+impl Ironsworn {
+  /// Action roll: d6 plus stat and adds, capped at 10, against the challenge dice
+  pub fn action(&mut self, stat: i16, adds: i16) -> IronswornResult {
+    let action = self.action.roll();
+    let score = (action.outcome + stat + adds).min(MAX_SCORE);
+    let challenge = self.challenge.roll();
+
+    IronswornResult::new(Some(action), score, challenge)
+  }
+
+  /// Progress roll: the progress score against the challenge dice
+  pub fn progress(&mut self, progress: i16) -> IronswornResult {
+    let challenge = self.challenge.roll();
+
+    IronswornResult::new(None, progress, challenge)
+  }
+}
@@ -0,0 +1,42 @@
+use super::Roller;
+
+// public functions implementation
+mod fn_roll;
+
+// unit tests
+mod ts_roll;
+
+/// highest action score, whatever the stat and adds
+const MAX_SCORE: i16 = 10;
+
+#[derive(Debug)]
+/// Ironsworn and Starforged moves: an action die or a progress score against two challenge dice
+pub struct Ironsworn {
+  /// one d6
+  action: Roller,
+
+  /// two d10
+  challenge: Roller,
+}
+
+impl Default for Ironsworn {
+  fn default() -> Self {
+    Self {
+      action: Roller::new(1, 6),
+      challenge: Roller::new(2, 10),
+    }
+  }
+}
+
+impl Ironsworn {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Seeds the random number generators, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.action = self.action.seed(seed);
+    self.challenge = self.challenge.seed(seed.wrapping_add(1));
+    self
+  }
+}
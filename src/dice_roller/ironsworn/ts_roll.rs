@@ -0,0 +1,71 @@
+#[test]
+fn outcome() {
+  assert_eq!(
+    super::super::IronswornOutcome::new(7, &[3, 6]),
+    super::super::IronswornOutcome::StrongHit
+  );
+  assert_eq!(
+    super::super::IronswornOutcome::new(7, &[3, 7]),
+    super::super::IronswornOutcome::WeakHit
+  );
+  assert_eq!(
+    super::super::IronswornOutcome::new(7, &[9, 7]),
+    super::super::IronswornOutcome::Miss
+  );
+}
+
+#[test]
+fn action() {
+  let mut r = super::Ironsworn::new();
+  for _ in 1..=1000 {
+    let result = r.action(2, 1);
+    let action = result.action.as_ref().unwrap();
+    assert_eq!(action.dice.len(), 1);
+    assert_eq!(result.score, (action.outcome + 3).min(10));
+    assert_eq!(result.challenge.dice.len(), 2);
+    result
+      .challenge
+      .dice
+      .iter()
+      .for_each(|x| assert!((1..=10).contains(x)));
+    assert_eq!(
+      result.outcome,
+      super::super::IronswornOutcome::new(result.score, &result.challenge.dice)
+    );
+    assert_eq!(
+      result.matched,
+      result.challenge.dice[0] == result.challenge.dice[1]
+    );
+  }
+}
+
+#[test]
+fn action_capped() {
+  let mut r = super::Ironsworn::new();
+  for _ in 1..=1000 {
+    let result = r.action(5, 4);
+    assert_eq!(result.score, 10);
+  }
+}
+
+#[test]
+fn progress() {
+  let mut r = super::Ironsworn::new();
+  for _ in 1..=1000 {
+    let result = r.progress(10);
+    assert!(result.action.is_none());
+    assert_eq!(result.score, 10);
+    if !result.challenge.dice.contains(&10) {
+      assert_eq!(result.outcome, super::super::IronswornOutcome::StrongHit);
+    }
+  }
+}
+
+#[test]
+fn seeded() {
+  let mut r1 = super::Ironsworn::new().seed(9);
+  let mut r2 = super::Ironsworn::new().seed(9);
+  for _ in 1..=100 {
+    assert_eq!(r1.action(2, 0).to_string(), r2.action(2, 0).to_string());
+  }
+}
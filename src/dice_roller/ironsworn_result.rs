@@ -0,0 +1,84 @@
+use super::RollResult;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// Outcome of an Ironsworn move
+pub enum IronswornOutcome {
+  StrongHit,
+  WeakHit,
+  Miss,
+}
+
+impl IronswornOutcome {
+  /// A strong hit beats both challenge dice, a weak hit only one. Ties go to the challenge dice
+  pub fn new(score: i16, challenge: &[u16]) -> Self {
+    let beaten = challenge.iter().filter(|x| score > **x as i16).count();
+    match beaten {
+      0 => IronswornOutcome::Miss,
+      1 => IronswornOutcome::WeakHit,
+      _ => IronswornOutcome::StrongHit,
+    }
+  }
+}
+
+impl fmt::Display for IronswornOutcome {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      IronswornOutcome::StrongHit => write!(f, "strong hit"),
+      IronswornOutcome::WeakHit => write!(f, "weak hit"),
+      IronswornOutcome::Miss => write!(f, "miss"),
+    }
+  }
+}
+
+/// A struct holding the result of an Ironsworn action or progress roll
+#[derive(Debug)]
+pub struct IronswornResult {
+  /// the action die, missing for progress rolls
+  pub action: Option<RollResult>,
+
+  /// action die plus stat and adds, capped at 10, or the progress score
+  pub score: i16,
+
+  /// the two challenge dice
+  pub challenge: RollResult,
+
+  pub outcome: IronswornOutcome,
+
+  /// whether the challenge dice show the same value
+  pub matched: bool,
+}
+
+impl IronswornResult {
+  pub fn new(action: Option<RollResult>, score: i16, challenge: RollResult) -> Self {
+    let outcome = IronswornOutcome::new(score, &challenge.dice);
+    let matched = challenge.dice.windows(2).all(|x| x[0] == x[1]);
+
+    Self {
+      action,
+      score,
+      challenge,
+      outcome,
+      matched,
+    }
+  }
+}
+
+/// "[4] 6 vs [3, 8] => weak hit", "7 vs [5, 5] => strong hit (match)"
+impl fmt::Display for IronswornResult {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(action) = &self.action {
+      write!(f, "{:?} ", action.dice)?;
+    }
+    write!(
+      f,
+      "{} vs {:?} => {}",
+      self.score, self.challenge.dice, self.outcome
+    )?;
+
+    match self.matched {
+      true => write!(f, " (match)"),
+      false => Ok(()),
+    }
+  }
+}
@@ -1,7 +1,31 @@
+mod band;
+mod contest;
+mod contest_result;
+mod criticals;
+mod deck;
+mod deck_err;
+mod distribution;
+mod draw_result;
+mod effect;
+mod ironsworn;
+mod ironsworn_result;
 mod roll_err;
 mod roll_result;
 mod roller;
+mod success_rule;
 
+pub use band::*;
+pub use contest::*;
+pub use contest_result::*;
+pub use criticals::*;
+pub use deck::*;
+pub use deck_err::*;
+pub use distribution::*;
+pub use draw_result::*;
+pub use effect::*;
+pub use ironsworn::*;
+pub use ironsworn_result::*;
 pub use roll_err::*;
 pub use roll_result::*;
 pub use roller::*;
+pub use success_rule::*;
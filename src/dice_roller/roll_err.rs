@@ -4,4 +4,11 @@ pub enum RollerErr {
   None,
   Generic,
   PossibleOverflow,
+  UnknownBands(String),
+
+  /// dice have at least one side
+  InvalidSides(u32),
+
+  /// dice exploding on every face, as "1d6 ex1" or an acing d1
+  EndlessExplosion,
 }
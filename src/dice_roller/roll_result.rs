@@ -1,22 +1,230 @@
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+/// Notable facts about a roll, on top of its outcome
+pub enum RollFlag {
+  ExceptionalSuccess,
+  DramaticFailure,
+  CriticalSuccess,
+  CriticalFailure,
+  Hitches(usize),
+  Botch,
+  Banes(usize),
+  Pushed,
+  Glitch,
+  CriticalGlitch,
+}
+
+impl fmt::Display for RollFlag {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      RollFlag::ExceptionalSuccess => write!(f, "exceptional success"),
+      RollFlag::DramaticFailure => write!(f, "dramatic failure"),
+      RollFlag::CriticalSuccess => write!(f, "critical success"),
+      RollFlag::CriticalFailure => write!(f, "critical failure"),
+      RollFlag::Hitches(1) => write!(f, "1 hitch"),
+      RollFlag::Hitches(hitches) => write!(f, "{} hitches", hitches),
+      RollFlag::Botch => write!(f, "botch"),
+      RollFlag::Banes(1) => write!(f, "1 bane"),
+      RollFlag::Banes(banes) => write!(f, "{} banes", banes),
+      RollFlag::Pushed => write!(f, "pushed"),
+      RollFlag::Glitch => write!(f, "glitch"),
+      RollFlag::CriticalGlitch => write!(f, "critical glitch"),
+    }
+  }
+}
+
 /// A struct holding dice results
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RollResult {
-  pub dice: Vec<u8>,
+  pub dice: Vec<u16>,
+
+  /// the size of the die behind every result
+  pub sides: Vec<u32>,
+
+  /// the colour of the die behind every result, empty when the pool has none
+  pub colours: Vec<String>,
+
   pub successes: Vec<i8>,
   pub outcome: i16,
+
+  /// the named result of the outcome, such as "partial"
+  pub band: Option<String>,
+
+  pub flags: Vec<RollFlag>,
+
+  /// the number the outcome had to reach, if any
+  pub target: Option<i16>,
+
+  /// whether the outcome had to be at or under the target instead
+  pub roll_under: bool,
+
+  /// every how much beyond the target is a raise, if raises are counted
+  pub raise_step: Option<i16>,
+
+  /// the size of the Cortex effect die, if any
+  pub effect: Option<u32>,
+
+  /// a success (true) or failure (false) whatever the margin, such as 17 and 18 in GURPS
+  pub automatic: Option<bool>,
 }
 
 impl RollResult {
-  pub fn new(dice: Vec<u8>, outcome: i16) -> Self {
+  pub fn new(dice: Vec<u16>, outcome: i16) -> Self {
     Self {
       dice,
       outcome,
+      sides: Vec::new(),
+      colours: Vec::new(),
       successes: Vec::new(),
+      band: None,
+      flags: Vec::new(),
+      target: None,
+      roll_under: false,
+      raise_step: None,
+      effect: None,
+      automatic: None,
     }
   }
 
+  pub fn sides(mut self, sides: Vec<u32>) -> Self {
+    self.sides = sides;
+    self
+  }
+
+  pub fn colours(mut self, colours: Vec<String>) -> Self {
+    self.colours = colours;
+    self
+  }
+
   pub fn successes(mut self, successes: Vec<i8>) -> Self {
     self.successes = successes;
     self
   }
+
+  pub fn band(mut self, band: Option<String>) -> Self {
+    self.band = band;
+    self
+  }
+
+  pub fn flags(mut self, flags: Vec<RollFlag>) -> Self {
+    self.flags = flags;
+    self
+  }
+
+  pub fn target(mut self, target: Option<i16>) -> Self {
+    self.target = target;
+    self
+  }
+
+  pub fn roll_under(mut self, roll_under: bool) -> Self {
+    self.roll_under = roll_under;
+    self
+  }
+
+  pub fn raise_step(mut self, raise_step: Option<i16>) -> Self {
+    self.raise_step = raise_step;
+    self
+  }
+
+  pub fn effect(mut self, effect: Option<u32>) -> Self {
+    self.effect = effect;
+    self
+  }
+
+  pub fn automatic(mut self, automatic: Option<bool>) -> Self {
+    self.automatic = automatic;
+    self
+  }
+
+  /// How far the outcome went beyond the target, negative when missed. Rolling under, that's
+  /// how far under the target it went. None without a target
+  pub fn margin(&self) -> Option<i16> {
+    let target = self.target?;
+    match self.roll_under {
+      true => Some(target - self.outcome),
+      false => Some(self.outcome - target),
+    }
+  }
+
+  /// Whether the target was reached, an automatic success or failure overriding the margin. None
+  /// without a target
+  pub fn success(&self) -> Option<bool> {
+    let margin = self.margin()?;
+    Some(self.automatic.unwrap_or(margin >= 0))
+  }
+
+  /// The number of raises, one for every raise step of margin. None when the target is missed or
+  /// raises aren't counted
+  pub fn raises(&self) -> Option<i16> {
+    match (self.margin(), self.success(), self.raise_step) {
+      (Some(margin), Some(true), Some(raise_step)) => Some(margin.max(0) / raise_step),
+      _ => None,
+    }
+  }
+}
+
+/// "[3, 4, 6] => 13", "[2, 5] => 5 => partial", "[1] => 0 (dramatic failure)",
+/// "[11, 2] => 11 => 1 raise", "[3, 2, 5] => 10 => success by 2", "[7, 1, 5] => 12 => effect d4 (1 hitch)",
+/// "base [6, 1] skill [3] => 1 (1 bane)"
+impl fmt::Display for RollResult {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.colours.is_empty() {
+      true => write!(f, "{:?}", self.dice)?,
+      false => {
+        // dice of the same colour together, in order of appearance
+        let mut colours: Vec<&String> = Vec::new();
+        self.colours.iter().for_each(|x| {
+          if !colours.contains(&x) {
+            colours.push(x);
+          }
+        });
+        let pools: Vec<String> = colours
+          .iter()
+          .map(|colour| {
+            let dice: Vec<u16> = self
+              .dice
+              .iter()
+              .zip(self.colours.iter())
+              .filter(|(_, x)| x == colour)
+              .map(|(x, _)| *x)
+              .collect();
+            format!("{} {:?}", colour, dice)
+          })
+          .collect();
+        write!(f, "{}", pools.join(" "))?
+      }
+    }
+    write!(f, " => {}", self.outcome)?;
+
+    if let Some(band) = &self.band {
+      write!(f, " => {}", band)?;
+    }
+
+    if let Some(effect) = self.effect {
+      write!(f, " => effect d{}", effect)?;
+    }
+
+    if let (Some(margin), Some(success)) = (self.margin(), self.success()) {
+      match (margin, success, self.raises()) {
+        (_, _, Some(0)) => write!(f, " => success")?,
+        (_, _, Some(1)) => write!(f, " => 1 raise")?,
+        (_, _, Some(raises)) => write!(f, " => {} raises", raises)?,
+        (0.., true, None) => write!(f, " => success by {}", margin)?,
+        (..=-1, false, None) => write!(f, " => failure by {}", -margin)?,
+        (_, true, None) => write!(f, " => automatic success")?,
+        (_, false, None) => write!(f, " => automatic failure")?,
+      }
+    }
+
+    match self.flags.is_empty() {
+      true => Ok(()),
+      false => {
+        let flags: Vec<String> = self.flags.iter().map(|x| x.to_string()).collect();
+        write!(f, " ({})", flags.join(", "))
+      }
+    }
+  }
 }
@@ -0,0 +1,112 @@
+use super::super::{Condition, Distribution};
+use super::Roller;
+use std::collections::BTreeMap;
+
+/// largest number of rolls enumerated one by one
+const MAX_ENUMERATED: u64 = 1_000_000;
+
+impl Roller {
+  /// The exact distribution of the outcome. Dice adding up or counting successes one by one are
+  /// combined die by die, other pools are enumerated roll by roll when small enough. Exploding,
+  /// acing and rerolled dice have none
+  pub fn distribution(&self) -> Option<Distribution> {
+    if let Some((min_dice, fallback)) = &self.fallback {
+      if self.dice < *min_dice {
+        return fallback.distribution();
+      }
+    }
+
+    if self.explode_threshold.is_some() || self.ace || self.rote {
+      return None;
+    }
+
+    self.independent().or_else(|| self.enumerated())
+  }
+
+  /// Whether every die shows a fair face, as opposed to acing dice adding up into a single result
+  pub fn fair_faces(&self) -> bool {
+    match &self.fallback {
+      Some((min_dice, fallback)) if self.dice < *min_dice => fallback.fair_faces(),
+      _ => !self.ace,
+    }
+  }
+
+  /// the sum of the value of every die, when no die affects the value of another
+  fn independent(&self) -> Option<Distribution> {
+    let per_die = self.success_rules.iter().all(|x| {
+      matches!(
+        x.condition,
+        Condition::Always | Condition::Success | Condition::Failure
+      )
+    });
+    let kept = self.take_max.is_some() || self.take_min.is_some() || self.take_mid.is_some();
+    if !per_die || kept || self.effect.is_some() {
+      return None;
+    }
+
+    let distribution = self
+      .pool()
+      .into_iter()
+      .map(|sides| self.die_distribution(sides))
+      .fold(Distribution::constant(0), |sum, x| sum.add(&x));
+
+    match self.success_threshold {
+      None => Some(distribution.shift(self.modifier.unwrap_or(0) as i16)),
+      Some(_) => Some(distribution),
+    }
+  }
+
+  /// the value of a single die, its face or its successes
+  fn die_distribution(&self, sides: u32) -> Distribution {
+    let Some(success_threshold) = self.success_threshold else {
+      return Distribution::uniform(sides);
+    };
+
+    let mut counts: BTreeMap<i16, u64> = BTreeMap::new();
+    for face in 1..=sides as u16 {
+      let value = self
+        .success_rules
+        .iter()
+        .find(|rule| rule.applies(face, success_threshold, &[face]))
+        .map(|rule| rule.value as i16)
+        .unwrap_or((face as u32 >= success_threshold) as i16);
+      *counts.entry(value).or_default() += 1;
+    }
+
+    Distribution::from_counts(&counts)
+  }
+
+  /// every possible roll worked out as a thrown one would be
+  fn enumerated(&self) -> Option<Distribution> {
+    let pool = self.pool();
+    let rolls = pool
+      .iter()
+      .try_fold(1_u64, |total, x| total.checked_mul(*x as u64))?;
+    if rolls > MAX_ENUMERATED || pool.iter().any(|x| *x > u16::MAX as u32) {
+      return None;
+    }
+
+    let colours = self.pool_colours();
+    let mut counts: BTreeMap<i16, u64> = BTreeMap::new();
+    let mut faces: Vec<u16> = vec![1; pool.len()];
+    loop {
+      let result = self.evaluate(faces.clone(), pool.clone(), colours.clone());
+      *counts.entry(result.outcome).or_default() += 1;
+
+      // next roll, as an odometer
+      let next = faces
+        .iter()
+        .zip(pool.iter())
+        .position(|(x, sides)| (*x as u32) < *sides);
+      match next {
+        None => break,
+        Some(position) => {
+          faces[position] += 1;
+          faces[..position].iter_mut().for_each(|x| *x = 1);
+        }
+      }
+    }
+
+    Some(Distribution::from_counts(&counts))
+  }
+}
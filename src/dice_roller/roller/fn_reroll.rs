@@ -0,0 +1,40 @@
+use super::super::{RollFlag, RollResult};
+use super::Roller;
+
+impl Roller {
+  /// Rerolls the dice of a previous result chosen by the predicate, keeping the others, and works
+  /// out the outcome again
+  pub fn reroll<P: Fn(u16) -> bool>(&mut self, result: &RollResult, predicate: P) -> RollResult {
+    // the previous result came from the fallback
+    if let Some((min_dice, fallback)) = &mut self.fallback {
+      if self.dice < *min_dice {
+        return fallback.reroll(result, predicate);
+      }
+    }
+
+    let mut results: Vec<u16> = Vec::new();
+    let mut sides: Vec<u32> = Vec::new();
+    let mut colours: Vec<String> = Vec::new();
+
+    for (index, die) in result.dice.iter().enumerate() {
+      let size = result.sides.get(index).copied().unwrap_or(self.sides);
+      let colour = result.colours.get(index).cloned().unwrap_or_default();
+      let die_results = match predicate(*die) {
+        true => self.roll_die(size),
+        false => vec![*die],
+      };
+      sides.extend(vec![size; die_results.len()]);
+      colours.extend(vec![colour; die_results.len()]);
+      results.extend(die_results);
+    }
+
+    self.evaluate(results, sides, colours)
+  }
+
+  /// Year Zero push: rerolls every die showing neither a 6 nor a 1
+  pub fn push(&mut self, result: &RollResult) -> RollResult {
+    let mut pushed = self.reroll(result, |x| x != 6 && x != 1);
+    pushed.flags.push(RollFlag::Pushed);
+    pushed
+  }
+}
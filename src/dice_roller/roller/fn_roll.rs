@@ -1,52 +1,92 @@
-use super::super::RollResult;
+use super::super::{RollFlag, RollResult};
 use super::Roller;
 
 impl Roller {
   /// Generates a roll result. The result will hold a Vector of die results as well as the sum
   pub fn roll(&mut self) -> RollResult {
-    let mut results: Vec<u8> = Vec::new();
+    // pool too small, rolling the fallback
+    if let Some((min_dice, fallback)) = &mut self.fallback {
+      if self.dice < *min_dice {
+        return fallback.roll();
+      }
+    }
+
+    let mut results: Vec<u16> = Vec::new();
+    let mut sides: Vec<u32> = Vec::new();
+    let mut colours: Vec<String> = Vec::new();
 
     // rolling dice and getting raw results
-    for _ in 1..=self.dice {
-      let die_results = self.roll_one();
+    for (die, colour) in self.pool().into_iter().zip(self.pool_colours()) {
+      let die_results = self.roll_die(die);
       for result in die_results {
         results.push(result);
+        sides.push(die);
+        colours.push(colour.clone());
       }
     }
 
+    // rerolling failed dice once
+    if let (true, Some(success_threshold)) = (self.rote, self.success_threshold) {
+      let failures: Vec<(u32, String)> = results
+        .iter()
+        .zip(sides.iter().zip(colours.iter()))
+        .filter(|(x, _)| (**x as u32) < success_threshold)
+        .map(|(_, (die, colour))| (*die, colour.clone()))
+        .collect();
+      for (die, colour) in failures {
+        let die_results = self.roll_die(die);
+        sides.extend(vec![die; die_results.len()]);
+        colours.extend(vec![colour; die_results.len()]);
+        results.extend(die_results);
+      }
+    }
+
+    self.evaluate(results, sides, colours)
+  }
+
+  /// Works out the outcome of raw die results, along with the size and the colour of every die
+  pub(super) fn evaluate(
+    &self,
+    results: Vec<u16>,
+    sides: Vec<u32>,
+    colours: Vec<String>,
+  ) -> RollResult {
     // standard roll
+    let total = saturating_sum(results.iter().map(|x| *x as i16));
     let mut sum: i16 = match self.modifier {
-      None => results.clone().into_iter().sum::<u8>() as i16,
-      Some(modifier) => results.clone().into_iter().sum::<u8>() as i16 + modifier as i16,
+      None => total,
+      Some(modifier) => total.saturating_add(modifier as i16),
     };
 
     // considering success counting
     let mut successes: Vec<i8> = Vec::new();
     if let Some(success_threshold) = self.success_threshold {
       results.iter().for_each(|x| {
-        if *x >= success_threshold as u8 {
-          let value = self.success_values.get(x);
-          let value = match value {
-            None => 1_i8,
-            Some(value) => *value,
-          };
-          successes.push(value);
-        } else {
-          let value = self.failure_values.get(x);
-          let value = match value {
-            None => 0_i8,
-            Some(value) => *value,
-          };
-          successes.push(value);
-        }
+        let value = self
+          .success_rules
+          .iter()
+          .find(|rule| rule.applies(*x, success_threshold, &results))
+          .map(|rule| rule.value);
+        let value = match value {
+          None if *x as u32 >= success_threshold => 1_i8,
+          None => 0_i8,
+          Some(value) => value,
+        };
+        successes.push(value);
       });
-      sum = successes.iter().sum::<i8>() as i16;
+      sum = saturating_sum(successes.iter().map(|x| *x as i16));
     }
 
     // considering the result array to analyze
-    let mut counting_results = match self.success_threshold {
-      None => results.iter().map(|x| *x as i8).collect(),
-      Some(_) => successes.clone(),
+    let mut counting_results: Vec<i16> = match self.success_threshold {
+      None => results.iter().map(|x| *x as i16).collect(),
+      Some(_) => successes.iter().map(|x| *x as i16).collect(),
+    };
+
+    // kept acing dice, as trait rolls and roll and keep, still get the modifier
+    let kept_modifier = match (self.ace, self.success_threshold) {
+      (true, None) => self.modifier.unwrap_or(0) as i16,
+      _ => 0,
     };
 
     // considering max
@@ -55,7 +95,8 @@ impl Roller {
       Some(max) => {
         counting_results.sort();
         counting_results.reverse();
-        counting_results.iter().take(max as usize).sum::<i8>() as i16
+        saturating_sum(counting_results.iter().take(max as usize).copied())
+          .saturating_add(kept_modifier)
       }
     };
 
@@ -64,7 +105,8 @@ impl Roller {
       None => sum,
       Some(min) => {
         counting_results.sort();
-        counting_results.iter().take(min as usize).sum::<i8>() as i16
+        saturating_sum(counting_results.iter().take(min as usize).copied())
+          .saturating_add(kept_modifier)
       }
     };
 
@@ -73,14 +115,106 @@ impl Roller {
       None => sum,
       Some(mid) => {
         counting_results.sort();
-        counting_results
-          .iter()
-          .skip((results.len() - mid as usize) / 2)
-          .take(mid as usize)
-          .sum::<i8>() as i16
+        // asking for more dice than rolled keeps them all
+        let skipped = results.len().saturating_sub(mid as usize) / 2;
+        saturating_sum(
+          counting_results
+            .iter()
+            .skip(skipped)
+            .take(mid as usize)
+            .copied(),
+        )
+        .saturating_add(kept_modifier)
+      }
+    };
+
+    // considering the Cortex total and effect die
+    let effect = self.effect.map(|strategy| {
+      let (total, effect) = strategy.select(&results, &sides);
+      sum = total.saturating_add(self.modifier.unwrap_or(0) as i16);
+      effect
+    });
+
+    // considering bands
+    let band = self
+      .bands
+      .iter()
+      .find(|x| x.contains(&results, sum))
+      .map(|x| x.label.clone());
+
+    // considering flags
+    let mut flags: Vec<RollFlag> = Vec::new();
+    let total_successes = saturating_sum(successes.iter().map(|x| *x as i16));
+    if let Some(exceptional_threshold) = self.exceptional_threshold {
+      if total_successes >= exceptional_threshold as i16 {
+        flags.push(RollFlag::ExceptionalSuccess);
       }
+    }
+    if self.dramatic_failure && total_successes <= 0 && results.contains(&1) {
+      flags.push(RollFlag::DramaticFailure);
+    }
+
+    if self.snake_eyes && results.iter().all(|x| *x == 1) {
+      flags.push(RollFlag::CriticalFailure);
+    }
+
+    let hitches = results.iter().filter(|x| **x == 1).count();
+    if self.effect.is_some() && hitches > 0 {
+      match hitches == results.len() {
+        true => flags.push(RollFlag::Botch),
+        false => flags.push(RollFlag::Hitches(hitches)),
+      }
+    }
+
+    // critical and automatic results of a roll-under check
+    let mut automatic = None;
+    if let (true, Some(target), Some(criticals)) = (self.roll_under, self.target, &self.criticals) {
+      flags.extend(criticals.check(target, sum));
+      automatic = criticals.automatic(sum);
+    }
+
+    // more than half the dice rolled showing the glitch face, exploded dice counted as well
+    if let Some(glitch) = self.glitch {
+      let count = results.iter().filter(|x| **x == glitch).count();
+      if count * 2 > results.len() {
+        match total_successes <= 0 {
+          true => flags.push(RollFlag::CriticalGlitch),
+          false => flags.push(RollFlag::Glitch),
+        }
+      }
+    }
+
+    // 1s are banes, unless on skill dice
+    let banes = results
+      .iter()
+      .zip(colours.iter())
+      .filter(|(x, colour)| **x == 1 && *colour != "skill")
+      .count();
+    if self.banes && banes > 0 {
+      flags.push(RollFlag::Banes(banes));
+    }
+
+    // the colours are kept only when the pool has some
+    let colours = match colours.iter().all(|x| x.is_empty()) {
+      true => Vec::new(),
+      false => colours,
     };
 
-    RollResult::new(results, sum).successes(successes)
+    RollResult::new(results, sum)
+      .sides(sides)
+      .colours(colours)
+      .target(self.target)
+      .roll_under(self.roll_under)
+      .raise_step(self.raise_step)
+      .effect(effect)
+      .automatic(automatic)
+      .successes(successes)
+      .band(band)
+      .flags(flags)
   }
 }
+
+/// the sum of the values, short of overflowing the outcome
+fn saturating_sum(values: impl Iterator<Item = i16>) -> i16 {
+  values.fold(0, |sum, x| sum.saturating_add(x))
+}
@@ -0,0 +1,47 @@
+use super::Roller;
+
+/// the most dice that can be rolled or kept
+const MAX_DICE: u32 = 10;
+
+impl Roller {
+  /// Ten dice rule: every two rolled dice over 10 become one kept die, then every kept die over
+  /// 10 becomes a +2 bonus. Kept dice can't outnumber rolled ones. Returns (rolled, kept, bonus)
+  pub fn ten_dice_rule(rolled: u32, kept: u32) -> (u32, u32, i32) {
+    let (rolled, kept) = match rolled > MAX_DICE {
+      true => (MAX_DICE, kept + (rolled - MAX_DICE) / 2),
+      false => (rolled, kept),
+    };
+
+    let (kept, bonus) = match kept > MAX_DICE {
+      true => (MAX_DICE, 2 * (kept - MAX_DICE) as i32),
+      false => (kept, 0),
+    };
+
+    (rolled, kept.min(rolled), bonus)
+  }
+
+  /// Rewrites roll and keep notation into the standard one, applying the ten dice rule. Exploding
+  /// dice add up into a single result. "7k3+2 ex9" => "7d10+2 ex9 max3 ex10 ace",
+  /// "12k4" => "10d10 max5 ex10 ace". None for any other definition
+  pub fn roll_and_keep(definition: &str) -> Option<String> {
+    let (dice, rest) = match definition.split_once(' ') {
+      Some((dice, rest)) => (dice, format!(" {}", rest)),
+      None => (definition, String::new()),
+    };
+
+    let modifier_start = dice.find(['+', '-']).unwrap_or(dice.len());
+    let (dice, modifier) = dice.split_at(modifier_start);
+    let (rolled, kept) = dice.split_once('k')?;
+    let (rolled, kept, bonus) = Roller::ten_dice_rule(rolled.parse().ok()?, kept.parse().ok()?);
+
+    let bonus = match bonus {
+      0 => String::new(),
+      bonus => format!("+{}", bonus),
+    };
+
+    Some(format!(
+      "{}d10{}{}{} max{} ex10 ace",
+      rolled, modifier, bonus, rest, kept
+    ))
+  }
+}
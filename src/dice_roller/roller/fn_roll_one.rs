@@ -1,26 +1,49 @@
 use super::Roller;
 use rand::Rng;
 
+/// most times a die explodes, keeping a roll bounded whatever its definition
+const MAX_EXPLOSIONS: usize = 100;
+
 impl Roller {
-  pub fn roll_one(&mut self) -> Vec<u8> {
-    let mut results: Vec<u8> = Vec::new();
+  pub fn roll_one(&mut self) -> Vec<u16> {
+    self.roll_die(self.sides)
+  }
+
+  /// rolls a single die of the given size, explosions included
+  pub(super) fn roll_die(&mut self, sides: u32) -> Vec<u16> {
+    // acing dice explode on their highest face, unless told otherwise
+    let explode_threshold = match (self.ace, self.explode_threshold) {
+      (true, None) => Some(sides),
+      (_, explode_threshold) => explode_threshold,
+    };
+
+    let mut results: Vec<u16> = Vec::new();
     loop {
-      let result = self.rng.gen_range(1..=self.sides) as u8;
+      let result = self.rng.gen_range(1..=sides) as u16;
 
       results.push(result);
 
       // check for exit conditions:
       // if there's now threshold or
       // the result is under the threshold
-      match self.explode_threshold {
+      match explode_threshold {
         None => break,
+        Some(_) if results.len() > MAX_EXPLOSIONS => break,
         Some(threshold) => {
-          if result < threshold as u8 {
+          if (result as u32) < threshold {
             break;
           }
         }
       }
     }
-    results
+
+    // an acing die is a single result, short of overflowing the outcome
+    match self.ace {
+      true => vec![results
+        .iter()
+        .fold(0_u16, |sum, x| sum.saturating_add(*x))
+        .min(i16::MAX as u16)],
+      false => results,
+    }
   }
 }
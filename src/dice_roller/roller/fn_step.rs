@@ -0,0 +1,64 @@
+use super::Roller;
+
+/// the die sizes a Cortex die steps through
+const STEPS: [u32; 5] = [4, 6, 8, 10, 12];
+
+impl Roller {
+  /// Steps one die of the given size up, d6 to d8 and so on. A d12 can't step up any further.
+  /// Stepping regroups the pool, dropping the colours
+  pub fn step_up(self, sides: u32) -> Self {
+    self.step(sides, 1)
+  }
+
+  /// Steps one die of the given size down, d8 to d6 and so on. A d4 stepped down is gone
+  pub fn step_down(self, sides: u32) -> Self {
+    self.step(sides, -1)
+  }
+
+  fn step(mut self, sides: u32, steps: i32) -> Self {
+    let mut pool = self.pool();
+    let Some(index) = pool.iter().position(|x| *x == sides) else {
+      return self;
+    };
+
+    match step(sides, steps) {
+      Some(stepped) => pool[index] = stepped,
+      None => {
+        pool.remove(index);
+      }
+    }
+
+    // regrouping the pool, main dice first
+    let mut groups: Vec<(u32, u32)> = Vec::new();
+    for die in pool {
+      match groups.last_mut() {
+        Some((dice, sides)) if *sides == die => *dice += 1,
+        _ => groups.push((1, die)),
+      }
+    }
+
+    let (dice, sides) = match groups.is_empty() {
+      true => (0, self.sides),
+      false => groups.remove(0),
+    };
+    self.dice = dice;
+    self.sides = sides;
+    self.groups = groups;
+    self.colours.clear();
+    self
+  }
+}
+
+/// moves a die size along the d4-d12 ladder, other sizes are left as they are
+fn step(sides: u32, steps: i32) -> Option<u32> {
+  match STEPS.iter().position(|x| *x == sides) {
+    None => Some(sides),
+    Some(position) => {
+      let position = (position as i32 + steps).min(STEPS.len() as i32 - 1);
+      match position < 0 {
+        true => None,
+        false => Some(STEPS[position as usize]),
+      }
+    }
+  }
+}
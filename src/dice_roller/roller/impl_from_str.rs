@@ -1,81 +1,161 @@
-use super::{Roller, RollerErr};
+use super::{Band, Roller, RollerErr};
+use std::collections::HashMap;
 use std::str::FromStr;
 /// for idiomatic parsing
 impl FromStr for Roller {
   type Err = RollerErr;
 
   fn from_str(descriptor: &str) -> Result<Roller, RollerErr> {
-    // handling single die scenario
-    let mut descriptor: String = String::from(descriptor);
-    if descriptor.starts_with('d') {
-      descriptor.insert(0, '1');
-    }
+    Roller::parse_with_bands(descriptor, &HashMap::new())
+  }
+}
 
-    // handling negative modifier
-    let sign = if descriptor.contains('-') { -1 } else { 1 };
+impl Roller {
+  /// Parses the definition, looking up "bands:name" among the given band tables before the
+  /// built-in ones
+  pub fn parse_with_bands(
+    descriptor: &str,
+    bands: &HashMap<String, Vec<Band>>,
+  ) -> Result<Roller, RollerErr> {
+    // roll and keep notation is rewritten into the standard one
+    let roll_and_keep = Roller::roll_and_keep(descriptor);
+    let descriptor = roll_and_keep.as_deref().unwrap_or(descriptor);
 
-    // tokenization of main part
-    let descriptor = descriptor.clone();
+    // Savage Worlds trait rolls are sugar over a heterogeneous pool
+    let savage_descriptor = Roller::parse_savage_descriptor(descriptor);
 
     // considering only the first part
-    let tokens: String = descriptor.split(&[' ']).take(1).collect::<String>();
+    let tokens: &str = match &savage_descriptor {
+      Some(tokens) => tokens,
+      None => descriptor.split(&[' ']).next().unwrap_or_default(),
+    };
 
-    // tokenisation
-    let tokens: Vec<_> = tokens
-      .split(&['d', '+', '-'])
-      .filter(|x| !x.is_empty())
-      .collect();
+    // tokenisation of dice groups and constants
+    let (groups, modifier) = Roller::parse_dice_descriptor(tokens)?;
+
+    // parsing colours of dice groups
+    let colour_descriptor = Roller::parse_colour_descriptor(tokens);
 
     // parsing success threshold
-    let success_descriptor = Roller::parse_success_descriptor(&descriptor);
+    let success_descriptor = Roller::parse_success_descriptor(descriptor);
 
     // parsing explode threshold
-    let explode_descriptor = Roller::parse_explode_descriptor(&descriptor);
+    let explode_descriptor = Roller::parse_explode_descriptor(descriptor);
 
     // parsing max N
-    let take_max_descriptor = Roller::parse_take_max_descriptor(&descriptor);
+    let take_max_descriptor = Roller::parse_take_max_descriptor(descriptor);
 
     // parsing min N
-    let take_min_descriptor = Roller::parse_take_min_descriptor(&descriptor);
+    let take_min_descriptor = Roller::parse_take_min_descriptor(descriptor);
 
     // parsing mid N
-    let take_mid_descriptor = Roller::parse_take_mid_descriptor(&descriptor);
+    let take_mid_descriptor = Roller::parse_take_mid_descriptor(descriptor);
 
-    // parsing success values
-    let success_values_descriptor = Roller::parse_success_values(&descriptor);
+    // parsing success and failure values
+    let success_rules_descriptor = Roller::parse_success_rules(descriptor)?;
 
-    // parsing failure values
-    let failure_values_descriptor = Roller::parse_failure_values(&descriptor);
+    // parsing rote
+    let rote_descriptor = Roller::parse_rote_descriptor(descriptor);
 
-    // output
-    let descriptor: (u32, i32, Option<i32>) = match tokens.len() {
-      2 => (
-        tokens[0].parse().map_err(|_| RollerErr::Generic)?,
-        tokens[1].parse().map_err(|_| RollerErr::Generic)?,
-        None,
-      ),
-      3 => (
-        tokens[0].parse().map_err(|_| RollerErr::Generic)?,
-        tokens[1].parse().map_err(|_| RollerErr::Generic)?,
-        Some(sign * tokens.last().unwrap().parse::<i32>().unwrap()),
-      ),
-      _ => todo!(),
-    };
+    // parsing storyteller mode
+    let storyteller_descriptor = Roller::parse_storyteller_descriptor(descriptor);
+
+    // parsing ace
+    let ace_descriptor = Roller::parse_ace_descriptor(descriptor);
+
+    // parsing target
+    let target_descriptor = Roller::parse_target_descriptor(descriptor);
+
+    // parsing effect die selection
+    let effect_descriptor = Roller::parse_effect_descriptor(descriptor);
+
+    // parsing die steps
+    let step_descriptor = Roller::parse_step_descriptor(descriptor);
+
+    // parsing Year Zero mode
+    let year_zero_descriptor = Roller::parse_year_zero_descriptor(descriptor);
+
+    // parsing glitch face
+    let glitch_descriptor = Roller::parse_glitch_descriptor(descriptor);
+
+    // parsing band table
+    let bands_descriptor = Roller::parse_bands_descriptor(descriptor, bands)?;
+
+    // parsing roll-under skill
+    let under_descriptor = Roller::parse_under_descriptor(descriptor);
 
-    if descriptor.0 * descriptor.1 as u32 > 2000 {
+    // parsing critical table
+    let criticals_descriptor = Roller::parse_criticals_descriptor(descriptor)?;
+
+    // output
+    let size: u64 = groups
+      .iter()
+      .map(|(dice, sides)| *dice as u64 * *sides as u64)
+      .sum();
+    if size > 2000 {
       return Err(RollerErr::PossibleOverflow);
     }
 
-    Ok(
-      Roller::new(descriptor.0, descriptor.1 as u32)
-        .modifier(descriptor.2)
-        .success_threshold(success_descriptor)
-        .explode_threshold(explode_descriptor)
-        .take_max(take_max_descriptor)
-        .take_min(take_min_descriptor)
-        .take_mid(take_mid_descriptor)
-        .add_success_values(success_values_descriptor)
-        .add_failure_values(failure_values_descriptor),
-    )
+    if let Some((_, sides)) = groups.iter().find(|(_, sides)| *sides == 0) {
+      return Err(RollerErr::InvalidSides(*sides));
+    }
+
+    let (dice, sides) = groups[0];
+    let roller = groups[1..]
+      .iter()
+      .fold(Roller::new(dice, sides), |roller, (dice, sides)| {
+        roller.group(*dice, *sides)
+      });
+
+    let roller = roller
+      .colours(colour_descriptor.iter().map(|x| x.as_str()).collect())
+      .modifier(modifier)
+      .success_threshold(success_descriptor)
+      .explode_threshold(explode_descriptor)
+      .take_max(take_max_descriptor)
+      .take_min(take_min_descriptor)
+      .take_mid(take_mid_descriptor)
+      .success_rules(success_rules_descriptor)
+      .rote(rote_descriptor)
+      .ace(ace_descriptor)
+      .target(target_descriptor)
+      .effect(effect_descriptor)
+      .glitch(glitch_descriptor)
+      .under(under_descriptor)
+      .criticals(criticals_descriptor);
+
+    let roller = match bands_descriptor {
+      Some(bands) => roller.bands(bands),
+      None => roller,
+    };
+
+    let roller = step_descriptor
+      .iter()
+      .fold(roller, |roller, (sides, up)| match up {
+        true => roller.step_up(*sides),
+        false => roller.step_down(*sides),
+      });
+
+    let roller = match year_zero_descriptor {
+      true => roller.year_zero(),
+      false => roller,
+    };
+
+    let roller = match (storyteller_descriptor, savage_descriptor) {
+      (true, _) => roller.storyteller(),
+      (false, Some(_)) => roller.savage(),
+      (false, None) => roller,
+    };
+
+    // a die exploding on every face would never stop
+    let endless = match (roller.explode_threshold, roller.ace) {
+      (Some(threshold), _) => threshold <= 1,
+      (None, true) => roller.pool().contains(&1),
+      (None, false) => false,
+    };
+    match endless {
+      true => Err(RollerErr::EndlessExplosion),
+      false => Ok(roller),
+    }
   }
 }
@@ -2,11 +2,28 @@ impl PartialEq for super::Roller {
   fn eq(&self, other: &super::Roller) -> bool {
     self.dice == other.dice
       && self.sides == other.sides
+      && self.groups == other.groups
+      && self.colours == other.colours
       && self.modifier == other.modifier
       && self.success_threshold == other.success_threshold
       && self.explode_threshold == other.explode_threshold
       && self.take_max == other.take_max
       && self.take_min == other.take_min
       && self.take_mid == other.take_mid
+      && self.success_rules == other.success_rules
+      && self.bands == other.bands
+      && self.fallback == other.fallback
+      && self.rote == other.rote
+      && self.exceptional_threshold == other.exceptional_threshold
+      && self.dramatic_failure == other.dramatic_failure
+      && self.ace == other.ace
+      && self.target == other.target
+      && self.roll_under == other.roll_under
+      && self.raise_step == other.raise_step
+      && self.criticals == other.criticals
+      && self.snake_eyes == other.snake_eyes
+      && self.effect == other.effect
+      && self.banes == other.banes
+      && self.glitch == other.glitch
   }
 }
@@ -1,18 +1,30 @@
-use rand::rngs::ThreadRng;
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::collections::HashMap;
 
 // public functions implementation
+mod fn_distribution;
+mod fn_reroll;
 mod fn_roll;
+mod fn_roll_and_keep;
 mod fn_roll_one;
+mod fn_step;
 mod impl_from_str;
 mod impl_partial_eq;
 
 // unit tests
+mod ts_distribution;
 mod ts_parse;
 mod ts_roll;
 
+use super::band::*;
+use super::criticals::*;
+use super::effect::*;
 use super::roll_err::*;
+use super::success_rule::*;
+
+/// groups of dice as (dice, sides)
+type Groups = Vec<(u32, u32)>;
 
 #[derive(Debug)]
 /// A die roller engine. Given a valid string such as "3d6", "d20", will generate a roll result.
@@ -23,6 +35,12 @@ pub struct Roller {
   /// number of side per die
   sides: u32,
 
+  /// further dice of other sizes rolled along, as (dice, sides)
+  groups: Groups,
+
+  /// optional name of every group of dice, main ones first, such as "base", "skill", "gear"
+  colours: Vec<String>,
+
   /// optional modifier per roll
   modifier: Option<i32>,
 
@@ -33,7 +51,7 @@ pub struct Roller {
   explode_threshold: Option<u32>,
 
   /// random number generator
-  rng: ThreadRng,
+  rng: StdRng,
 
   /// maximum number of dice to consider for outcome
   take_max: Option<u32>,
@@ -44,11 +62,51 @@ pub struct Roller {
   /// mid number of dice to consider for outcome
   take_mid: Option<u32>,
 
-  /// hash with success values
-  success_values: HashMap<u8, i8>,
+  /// ordered rules setting the value of dice in successes, the first one applying wins
+  success_rules: Vec<SuccessRule>,
+
+  /// named results of the outcome, the first matching one applies
+  bands: Vec<Band>,
+
+  /// roller used instead when the pool has fewer dice than the given number
+  fallback: Option<(u32, Box<Roller>)>,
+
+  /// whether failed dice are rerolled once
+  rote: bool,
+
+  /// optional number of successes making an exceptional success
+  exceptional_threshold: Option<u32>,
+
+  /// whether no successes and a die showing 1 make a dramatic failure
+  dramatic_failure: bool,
+
+  /// whether every die explodes on its highest face, or on the explode threshold if any, adding
+  /// up into a single result
+  ace: bool,
+
+  /// optional number the outcome has to reach
+  target: Option<i16>,
+
+  /// whether the outcome has to be at or under the target instead
+  roll_under: bool,
+
+  /// optional margin over the target making a raise
+  raise_step: Option<i16>,
+
+  /// optional table of critical results of a roll-under check
+  criticals: Option<Criticals>,
+
+  /// whether every die showing 1 makes a critical failure
+  snake_eyes: bool,
+
+  /// optional Cortex selection of a total and an effect die
+  effect: Option<EffectStrategy>,
 
-  /// hash with failure values
-  failure_values: HashMap<u8, i8>,
+  /// whether 1s are reported as Year Zero banes
+  banes: bool,
+
+  /// optional face making a glitch when more than half the pool shows it
+  glitch: Option<u16>,
 }
 
 impl Roller {
@@ -57,16 +115,187 @@ impl Roller {
     Self {
       dice,
       sides,
+      groups: Vec::new(),
+      colours: Vec::new(),
       modifier: None,
       success_threshold: None,
       explode_threshold: None,
       take_max: None,
       take_min: None,
       take_mid: None,
-      rng: thread_rng(),
-      success_values: HashMap::new(),
-      failure_values: HashMap::new(),
+      rng: StdRng::from_entropy(),
+      success_rules: Vec::new(),
+      bands: Vec::new(),
+      fallback: None,
+      rote: false,
+      exceptional_threshold: None,
+      dramatic_failure: false,
+      ace: false,
+      target: None,
+      roll_under: false,
+      raise_step: None,
+      criticals: None,
+      snake_eyes: false,
+      effect: None,
+      banes: false,
+      glitch: None,
+    }
+  }
+
+  /// Seeds the random number generator, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self.fallback = self
+      .fallback
+      .map(|(dice, fallback)| (dice, Box::new(fallback.seed(seed.wrapping_add(1)))));
+    self
+  }
+
+  /// Maps outcome ranges to named results. The first band containing the outcome applies
+  pub fn bands(mut self, bands: Vec<Band>) -> Self {
+    self.bands = bands;
+    self
+  }
+
+  /// Rolls the fallback instead when the pool has fewer dice than min_dice, such as 2d6 keeping
+  /// the lowest for a zero dice pool
+  pub fn fallback(mut self, min_dice: u32, fallback: Roller) -> Self {
+    self.fallback = Some((min_dice, Box::new(fallback)));
+    self
+  }
+
+  /// Rolls further dice of another size along with the main ones, such as the wild die of a
+  /// Savage Worlds trait roll
+  pub fn group(mut self, dice: u32, sides: u32) -> Self {
+    self.groups.push((dice, sides));
+    self
+  }
+
+  /// Makes every die explode on its highest face, or on the explode threshold if any, adding up
+  /// into a single result
+  pub fn ace(mut self, ace: bool) -> Self {
+    self.ace = ace;
+    self
+  }
+
+  /// Sets the number the outcome has to reach. The result shows the margin
+  pub fn target(mut self, target: Option<i16>) -> Self {
+    self.target = target;
+    self
+  }
+
+  /// Makes the check a roll-under one: the outcome has to be at or under the skill
+  pub fn under(mut self, skill: Option<i16>) -> Self {
+    if skill.is_some() {
+      self.target = skill;
+      self.roll_under = true;
     }
+    self
+  }
+
+  /// Flags critical successes and failures of a roll-under check by the table
+  pub fn criticals(mut self, criticals: Option<Criticals>) -> Self {
+    self.criticals = criticals;
+    self
+  }
+
+  /// Names every group of dice, main ones first. The result keeps the colour of every die
+  pub fn colours(mut self, colours: Vec<&str>) -> Self {
+    self.colours = colours.into_iter().map(String::from).collect();
+    self
+  }
+
+  /// Cortex pool semantics: dice showing 1 are hitches, the rest is split into a total and an
+  /// effect die by the strategy
+  pub fn effect(mut self, effect: Option<EffectStrategy>) -> Self {
+    self.effect = effect;
+    self
+  }
+
+  /// Reports a glitch when more than half the pool shows the face, a critical glitch when there
+  /// are no successes as well
+  pub fn glitch(mut self, glitch: Option<u16>) -> Self {
+    self.glitch = glitch;
+    self
+  }
+
+  fn rote(mut self, rote: bool) -> Self {
+    self.rote = rote;
+    self
+  }
+
+  fn dramatic_failure(mut self, dramatic_failure: bool) -> Self {
+    self.dramatic_failure = dramatic_failure;
+    self
+  }
+
+  /// Storyteller pool semantics: successes on 8 or more, 10-again, exceptional success with 5
+  /// successes. Pools under one die roll a chance die instead. Explicit thresholds are kept
+  fn storyteller(mut self) -> Self {
+    self.success_threshold.get_or_insert(8);
+    self.explode_threshold.get_or_insert(10);
+    self.exceptional_threshold.get_or_insert(5);
+
+    // the chance die succeeds only on 10, while a 1 is a dramatic failure
+    let chance = Roller::new(1, self.sides)
+      .success_threshold(Some(self.sides))
+      .dramatic_failure(true);
+    self.fallback(1, chance)
+  }
+
+  /// Savage Worlds trait roll: the trait die and the optional wild die ace, the higher one is
+  /// kept and the target is 4 unless given. Snake eyes are a critical failure with a wild die
+  fn savage(mut self) -> Self {
+    self.ace = true;
+    self.take_max = Some(1);
+    self.target.get_or_insert(4);
+    self.raise_step = Some(4);
+    self.snake_eyes = !self.groups.is_empty();
+    self
+  }
+
+  /// Year Zero pool semantics: successes on 6, 1s are banes except on skill dice. Unnamed
+  /// groups are called base, skill and gear
+  fn year_zero(mut self) -> Self {
+    self.success_threshold.get_or_insert(6);
+    self.banes = true;
+    if self.colours.iter().all(|x| x.is_empty()) {
+      self.colours = vec![
+        String::from("base"),
+        String::from("skill"),
+        String::from("gear"),
+      ];
+    }
+    self
+  }
+
+  /// the colour of every die to roll, empty when the group has no name
+  fn pool_colours(&self) -> Vec<String> {
+    let mut colours: Vec<String> = Vec::new();
+    let dice = std::iter::once(self.dice).chain(self.groups.iter().map(|x| x.0));
+    for (index, dice) in dice.enumerate() {
+      let colour = self.colours.get(index).cloned().unwrap_or_default();
+      colours.extend(vec![colour; dice as usize]);
+    }
+    colours
+  }
+
+  /// The number of dice to roll, every group included
+  pub fn pool_size(&self) -> u64 {
+    self
+      .groups
+      .iter()
+      .fold(self.dice as u64, |total, (dice, _)| total + *dice as u64)
+  }
+
+  /// the size of every die to roll, main ones first
+  fn pool(&self) -> Vec<u32> {
+    let mut pool = vec![self.sides; self.dice as usize];
+    self
+      .groups
+      .iter()
+      .for_each(|(dice, sides)| pool.extend(vec![*sides; *dice as usize]));
+    pool
   }
 
   fn modifier(mut self, modifier: Option<i32>) -> Self {
@@ -74,22 +303,22 @@ impl Roller {
     self
   }
 
-  fn success_threshold(mut self, success_threshold: Option<u32>) -> Self {
+  pub fn success_threshold(mut self, success_threshold: Option<u32>) -> Self {
     self.success_threshold = success_threshold;
     self
   }
 
-  fn explode_threshold(mut self, explode_threshold: Option<u32>) -> Self {
+  pub fn explode_threshold(mut self, explode_threshold: Option<u32>) -> Self {
     self.explode_threshold = explode_threshold;
     self
   }
 
-  fn take_max(mut self, take_max: Option<u32>) -> Self {
+  pub fn take_max(mut self, take_max: Option<u32>) -> Self {
     self.take_max = take_max;
     self
   }
 
-  fn take_min(mut self, take_min: Option<u32>) -> Self {
+  pub fn take_min(mut self, take_min: Option<u32>) -> Self {
     self.take_min = take_min;
     self
   }
@@ -99,27 +328,14 @@ impl Roller {
     self
   }
 
-  fn add_success_values(mut self, values: Option<Vec<(u8, i8)>>) -> Self {
-    if let Some(values) = values {
-      values.iter().for_each(|x| {
-        self.success_values.insert(x.0, x.1);
-      });
-    } else {
-      self.success_values.clear();
-    }
-
+  /// Adds a rule setting the value of dice in successes. Rules are checked in order
+  pub fn success_rule(mut self, rule: SuccessRule) -> Self {
+    self.success_rules.push(rule);
     self
   }
 
-  fn add_failure_values(mut self, values: Option<Vec<(u8, i8)>>) -> Self {
-    if let Some(values) = values {
-      values.iter().for_each(|x| {
-        self.failure_values.insert(x.0, x.1);
-      });
-    } else {
-      self.failure_values.clear();
-    }
-
+  fn success_rules(mut self, rules: Vec<SuccessRule>) -> Self {
+    self.success_rules = rules;
     self
   }
 
@@ -198,51 +414,194 @@ impl Roller {
     take_mid_descriptor
   }
 
-  fn parse_success_values(descriptor: &str) -> Option<Vec<(u8, i8)>> {
-    let mut success_values: Vec<(u8, i8)> = Vec::new();
+  fn parse_rote_descriptor(descriptor: &str) -> bool {
+    // rote handling
+    descriptor.split(&[' ']).any(|x| x == "rote")
+  }
 
+  fn parse_target_descriptor(descriptor: &str) -> Option<i16> {
+    // tnN handling
     descriptor
       .split(&[' '])
-      .filter(|x| x.contains("sv"))
-      .map(|x| {
-        let tokens: Vec<_> = x.split(&[':']).collect();
-        (
-          tokens[1].parse::<u8>().unwrap(),
-          tokens[2].parse::<i8>().unwrap(),
-        )
-      })
-      .for_each(|x| {
-        success_values.insert(0, x);
-      });
+      .filter_map(|x| x.strip_prefix("tn"))
+      .find_map(|x| x.parse::<i16>().ok())
+  }
 
-    if success_values.is_empty() {
-      return None;
-    }
+  fn parse_effect_descriptor(descriptor: &str) -> Option<EffectStrategy> {
+    // effect and effect:die handling
+    descriptor.split(&[' ']).find_map(|x| match x {
+      "effect" => Some(EffectStrategy::BestTotal),
+      "effect:die" => Some(EffectStrategy::BestEffect),
+      _ => None,
+    })
+  }
 
-    Some(success_values)
+  /// "up:d6 down:d10": the die sizes to step, in order, with the direction
+  fn parse_step_descriptor(descriptor: &str) -> Vec<(u32, bool)> {
+    descriptor
+      .split(&[' '])
+      .filter_map(|x| match x.split_once(":d") {
+        Some(("up", sides)) => Some((sides, true)),
+        Some(("down", sides)) => Some((sides, false)),
+        _ => None,
+      })
+      .filter_map(|(sides, up)| Some((sides.parse().ok()?, up)))
+      .collect()
   }
 
-  fn parse_failure_values(descriptor: &str) -> Option<Vec<(u8, i8)>> {
-    let mut failure_values: Vec<(u8, i8)> = Vec::new();
+  fn parse_glitch_descriptor(descriptor: &str) -> Option<u16> {
+    // glN handling
+    descriptor
+      .split(&[' '])
+      .filter_map(|x| x.strip_prefix("gl"))
+      .find_map(|x| x.parse::<u16>().ok())
+  }
 
+  fn parse_bands_descriptor(
+    descriptor: &str,
+    bands: &HashMap<String, Vec<Band>>,
+  ) -> Result<Option<Vec<Band>>, RollerErr> {
+    // bands:name handling
+    let Some(name) = descriptor
+      .split(&[' '])
+      .find_map(|x| x.strip_prefix("bands:"))
+    else {
+      return Ok(None);
+    };
+
+    match bands.get(name).cloned().or(Band::preset(name)) {
+      Some(bands) => Ok(Some(bands)),
+      None => Err(RollerErr::UnknownBands(String::from(name))),
+    }
+  }
+
+  fn parse_under_descriptor(descriptor: &str) -> Option<i16> {
+    // underN handling
     descriptor
       .split(&[' '])
-      .filter(|x| x.contains("fv"))
-      .map(|x| {
-        let tokens: Vec<_> = x.split(&[':']).collect();
-        (
-          tokens[1].parse::<u8>().unwrap(),
-          tokens[2].parse::<i8>().unwrap(),
-        )
-      })
-      .for_each(|x| {
-        failure_values.insert(0, x);
-      });
+      .filter_map(|x| x.strip_prefix("under"))
+      .find_map(|x| x.parse::<i16>().ok())
+  }
+
+  fn parse_criticals_descriptor(descriptor: &str) -> Result<Option<Criticals>, RollerErr> {
+    // crit:name handling
+    match descriptor
+      .split(&[' '])
+      .find_map(|x| x.strip_prefix("crit:"))
+    {
+      None => Ok(None),
+      Some(name) => Criticals::preset(name).map(Some).ok_or(RollerErr::Generic),
+    }
+  }
+
+  fn parse_ace_descriptor(descriptor: &str) -> bool {
+    // ace handling
+    descriptor.split(&[' ']).any(|x| x == "ace")
+  }
+
+  /// "trait d8+1 wild d6" is rolled as "d8+1+d6" with Savage Worlds semantics
+  fn parse_savage_descriptor(descriptor: &str) -> Option<String> {
+    let tokens: Vec<&str> = descriptor.split(&[' ']).collect();
+    let trait_die = match tokens.as_slice() {
+      ["trait", trait_die, ..] => trait_die,
+      _ => return None,
+    };
+
+    let wild_die = tokens
+      .windows(2)
+      .find(|x| x[0] == "wild")
+      .map(|x| format!("+{}", x[1]))
+      .unwrap_or_default();
+
+    Some(format!("{}{}", trait_die, wild_die))
+  }
+
+  /// "3d6+2", "d8+d10+2d6", "d8+1+d6": groups of dice as (dice, sides) and the sum of the
+  /// constants, if any
+  fn parse_dice_descriptor(descriptor: &str) -> Result<(Groups, Option<i32>), RollerErr> {
+    let mut terms: Vec<String> = Vec::new();
+    for c in descriptor.chars() {
+      match c {
+        '+' | '-' => terms.push(String::from(c)),
+        _ => match terms.last_mut() {
+          Some(term) => term.push(c),
+          None => terms.push(String::from(c)),
+        },
+      }
+    }
 
-    if failure_values.is_empty() {
-      return None;
+    let mut groups: Groups = Vec::new();
+    let mut modifier: Option<i32> = None;
+    for term in terms.iter().filter(|x| !matches!(x.as_str(), "+" | "-")) {
+      // dropping the colour
+      let term = term.split(':').next().unwrap_or_default();
+      match term.trim_start_matches('+').split_once('d') {
+        Some((dice, sides)) => {
+          let dice = match dice {
+            "" => 1,
+            _ => dice.parse::<u32>().map_err(|_| RollerErr::Generic)?,
+          };
+          groups.push((dice, sides.parse().map_err(|_| RollerErr::Generic)?));
+        }
+        None => {
+          let constant = term.parse::<i32>().map_err(|_| RollerErr::Generic)?;
+          modifier = Some(modifier.unwrap_or(0) + constant);
+        }
+      }
     }
 
-    Some(failure_values)
+    match groups.is_empty() {
+      true => Err(RollerErr::Generic),
+      false => Ok((groups, modifier)),
+    }
+  }
+
+  /// "3d6:base+2d6:skill": the colour of every group of dice, empty when none has one
+  fn parse_colour_descriptor(descriptor: &str) -> Vec<String> {
+    let colours: Vec<String> = descriptor
+      .split(&['+', '-'])
+      .filter(|x| x.contains('d'))
+      .map(|x| x.split_once(':').map(|x| x.1).unwrap_or_default())
+      .map(String::from)
+      .collect();
+
+    match colours.iter().all(|x| x.is_empty()) {
+      true => Vec::new(),
+      false => colours,
+    }
+  }
+
+  fn parse_year_zero_descriptor(descriptor: &str) -> bool {
+    // yz handling
+    descriptor.split(&[' ']).any(|x| x == "yz")
+  }
+
+  fn parse_storyteller_descriptor(descriptor: &str) -> bool {
+    // wod handling
+    descriptor.split(&[' ']).any(|x| x == "wod")
+  }
+
+  /// "sv:6:2" values successes showing 6 as 2, "fv:1:-1" failures showing 1 as -1 and
+  /// "rule:>=9:2" is a rule of its own. Rules are kept in order of appearance
+  fn parse_success_rules(descriptor: &str) -> Result<Vec<SuccessRule>, RollerErr> {
+    descriptor
+      .split(&[' '])
+      .filter_map(|x| {
+        if let Some(rule) = x.strip_prefix("sv:") {
+          Some((rule, Some(Condition::Success)))
+        } else if let Some(rule) = x.strip_prefix("fv:") {
+          Some((rule, Some(Condition::Failure)))
+        } else {
+          x.strip_prefix("rule:").map(|rule| (rule, None))
+        }
+      })
+      .map(|(rule, condition)| {
+        let rule: SuccessRule = rule.parse()?;
+        Ok(match condition {
+          Some(condition) => rule.condition(condition),
+          None => rule,
+        })
+      })
+      .collect()
   }
 }
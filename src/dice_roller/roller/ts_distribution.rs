@@ -0,0 +1,73 @@
+#[test]
+fn sum() {
+  let r: super::Roller = String::from("2d6+1").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.probability(8) - 6.0 / 36.0).abs() < 1e-9);
+  assert!((d.mean() - 8.0).abs() < 1e-9);
+  assert_eq!(d.probability(2), 0.0);
+}
+
+#[test]
+fn large_sum() {
+  let r: super::Roller = String::from("3d100").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.mean() - 151.5).abs() < 1e-9);
+  assert!((d.at_most(300) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn kept_dice() {
+  let r: super::Roller = String::from("4d6 max3").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.probability(18) - 21.0 / 1296.0).abs() < 1e-9);
+  assert!((d.mean() - 15869.0 / 1296.0).abs() < 1e-9);
+
+  let r: super::Roller = String::from("2d20 max1").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.probability(20) - 39.0 / 400.0).abs() < 1e-9);
+}
+
+#[test]
+fn successes() {
+  let r: super::Roller = String::from("10d10 sc8").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.mean() - 3.0).abs() < 1e-9);
+  assert!((d.probability(0) - 0.7_f64.powi(10)).abs() < 1e-9);
+
+  let r: super::Roller = String::from("2d10 sc7 rule:=10:2").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.probability(4) - 0.01).abs() < 1e-9);
+}
+
+#[test]
+fn matching_rules() {
+  let r: super::Roller = String::from("2d10 sc7 rule:=10:3:m2").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.probability(6) - 0.01).abs() < 1e-9);
+}
+
+#[test]
+fn percentile() {
+  let r: super::Roller = String::from("1d20").parse().unwrap();
+  let d = r.distribution().unwrap();
+  assert!((d.percentile(20) - 0.975).abs() < 1e-9);
+  assert!((d.percentile(1) - 0.025).abs() < 1e-9);
+}
+
+#[test]
+fn none() {
+  let r: super::Roller = String::from("3d6 ex6").parse().unwrap();
+  assert_eq!(r.distribution(), None);
+
+  let r: super::Roller = String::from("10d10 max3").parse().unwrap();
+  assert_eq!(r.distribution(), None);
+}
+
+#[test]
+fn fair_faces() {
+  let r: super::Roller = String::from("3d6 ex6").parse().unwrap();
+  assert!(r.fair_faces());
+
+  let r: super::Roller = String::from("3d6 ace").parse().unwrap();
+  assert!(!r.fair_faces());
+}
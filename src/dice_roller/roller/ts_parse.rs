@@ -82,6 +82,32 @@ fn overflow_check() {
   assert_eq!(r, Err(super::RollerErr::PossibleOverflow));
 }
 
+#[test]
+fn endless_explosion() {
+  for definition in [
+    "1d6 ex1",
+    "1d6 ex0",
+    "d1 ace",
+    "trait d1",
+    "trait d6 wild d1",
+  ] {
+    let r: Result<super::Roller, super::RollerErr> = String::from(definition).parse();
+    assert_eq!(r, Err(super::RollerErr::EndlessExplosion), "{}", definition);
+  }
+
+  let r: Result<super::Roller, super::RollerErr> = String::from("1d2 ex2").parse();
+  assert!(r.is_ok());
+}
+
+#[test]
+fn invalid_sides() {
+  let r: Result<super::Roller, super::RollerErr> = String::from("3d6+1d0").parse();
+  assert_eq!(r, Err(super::RollerErr::InvalidSides(0)));
+
+  let r: super::Roller = String::from("1d1000").parse().unwrap();
+  assert_eq!(r.sides, 1000);
+}
+
 #[test]
 fn partial_eq() {
   let r1: super::Roller = String::from("3d6").parse().unwrap();
@@ -98,35 +124,233 @@ fn partial_eq() {
 #[test]
 fn success_values() {
   let r: super::Roller = String::from("3d6 sc5 sv:6:2").parse().unwrap();
-  let value = r.success_values.get(&6);
-  assert_eq!(value, Option::Some(&2));
-  assert_eq!(r.failure_values.len(), 0);
+  assert_eq!(
+    r.success_rules,
+    vec![
+      super::SuccessRule::new(super::Comparison::Equal, 6, 2).condition(super::Condition::Success)
+    ]
+  );
 }
 
 #[test]
 fn multi_success_values() {
   let r: super::Roller = String::from("3d6 sc5 sv:6:2 sv:5:1").parse().unwrap();
-  let value = r.success_values.get(&5);
-  assert_eq!(value, Option::Some(&1));
-  let value = r.success_values.get(&6);
-  assert_eq!(value, Option::Some(&2));
-  assert_eq!(r.failure_values.len(), 0);
+  assert_eq!(
+    r.success_rules,
+    vec![
+      super::SuccessRule::new(super::Comparison::Equal, 6, 2).condition(super::Condition::Success),
+      super::SuccessRule::new(super::Comparison::Equal, 5, 1).condition(super::Condition::Success),
+    ]
+  );
 }
 
 #[test]
 fn failure_values() {
   let r: super::Roller = String::from("3d6 sc5 fv:1:-1").parse().unwrap();
-  let value = r.failure_values.get(&1);
-  assert_eq!(value, Option::Some(&-1));
-  assert_eq!(r.success_values.len(), 0);
+  assert_eq!(
+    r.success_rules,
+    vec![
+      super::SuccessRule::new(super::Comparison::Equal, 1, -1).condition(super::Condition::Failure)
+    ]
+  );
 }
 
 #[test]
 fn multi_failure_values() {
   let r: super::Roller = String::from("3d6 sc5 fv:1:-2 fv:2:-1").parse().unwrap();
-  let value = r.failure_values.get(&1);
-  assert_eq!(value, Option::Some(&-2));
-  let value = r.failure_values.get(&2);
-  assert_eq!(value, Option::Some(&-1));
-  assert_eq!(r.success_values.len(), 0);
+  assert_eq!(
+    r.success_rules,
+    vec![
+      super::SuccessRule::new(super::Comparison::Equal, 1, -2).condition(super::Condition::Failure),
+      super::SuccessRule::new(super::Comparison::Equal, 2, -1).condition(super::Condition::Failure),
+    ]
+  );
+}
+
+#[test]
+fn success_rules() {
+  let r: super::Roller = String::from("8d10 sc7 rule:=10:3:m2 rule:>=9:2 rule:<=1:-1:t1")
+    .parse()
+    .unwrap();
+  assert_eq!(
+    r.success_rules,
+    vec![
+      super::SuccessRule::new(super::Comparison::Equal, 10, 3)
+        .condition(super::Condition::Matching(2)),
+      super::SuccessRule::new(super::Comparison::AtLeast, 9, 2),
+      super::SuccessRule::new(super::Comparison::AtMost, 1, -1)
+        .condition(super::Condition::Successes(1)),
+    ]
+  );
+
+  let r: Result<super::Roller, super::RollerErr> = String::from("8d10 sc7 rule:>=9:x").parse();
+  assert_eq!(r, Err(super::RollerErr::Generic));
+  let r: Result<super::Roller, super::RollerErr> = String::from("8d10 sc7 rule:=10:2:").parse();
+  assert_eq!(r, Err(super::RollerErr::Generic));
+}
+
+#[test]
+fn invalid_definition() {
+  let r: Result<super::Roller, super::RollerErr> = String::from("goblins").parse();
+  assert_eq!(r, Err(super::RollerErr::Generic));
+
+  let r: Result<super::Roller, super::RollerErr> = String::from("3d6+x").parse();
+  assert_eq!(r, Err(super::RollerErr::Generic));
+}
+
+#[test]
+fn rote() {
+  let r: super::Roller = String::from("5d10 sc8 rote").parse().unwrap();
+  assert!(r.rote);
+}
+
+#[test]
+fn storyteller() {
+  let r: super::Roller = String::from("5d10 wod").parse().unwrap();
+  assert_eq!(r.success_threshold, Some(8));
+  assert_eq!(r.explode_threshold, Some(10));
+  assert_eq!(r.exceptional_threshold, Some(5));
+  assert!(r.fallback.is_some());
+
+  let r: super::Roller = String::from("5d10 wod ex9").parse().unwrap();
+  assert_eq!(r.explode_threshold, Some(9));
+}
+
+#[test]
+fn heterogeneous_pool() {
+  let r: super::Roller = String::from("d8+d10+2d6+1").parse().unwrap();
+  assert_eq!(r.dice, 1);
+  assert_eq!(r.sides, 8);
+  assert_eq!(r.groups, vec![(1, 10), (2, 6)]);
+  assert_eq!(r.modifier, Some(1));
+  assert_eq!(r.pool(), vec![8, 10, 6, 6]);
+}
+
+#[test]
+fn savage() {
+  let r: super::Roller = String::from("trait d8+1 wild d6").parse().unwrap();
+  assert_eq!(r.sides, 8);
+  assert_eq!(r.groups, vec![(1, 6)]);
+  assert_eq!(r.modifier, Some(1));
+  assert_eq!(r.take_max, Some(1));
+  assert_eq!(r.target, Some(4));
+  assert!(r.ace);
+  assert!(r.snake_eyes);
+
+  let r: super::Roller = String::from("trait d6-2 tn6").parse().unwrap();
+  assert!(r.groups.is_empty());
+  assert_eq!(r.modifier, Some(-2));
+  assert_eq!(r.target, Some(6));
+  assert!(!r.snake_eyes);
+}
+
+#[test]
+fn effect() {
+  let r: super::Roller = String::from("d8+d10+2d6 effect").parse().unwrap();
+  assert_eq!(r.effect, Some(super::EffectStrategy::BestTotal));
+
+  let r: super::Roller = String::from("d8+d10+2d6 effect:die").parse().unwrap();
+  assert_eq!(r.effect, Some(super::EffectStrategy::BestEffect));
+}
+
+#[test]
+fn step() {
+  let r: super::Roller = String::from("d8+d10+2d6 up:d6 up:d12 down:d10")
+    .parse()
+    .unwrap();
+  assert_eq!(r.pool(), vec![8, 8, 8, 6]);
+
+  let r: super::Roller = String::from("d4+d6 down:d4").parse().unwrap();
+  assert_eq!(r.pool(), vec![6]);
+
+  let r: super::Roller = String::from("d12 up:d12").parse().unwrap();
+  assert_eq!(r.pool(), vec![12]);
+}
+
+#[test]
+fn colours() {
+  let r: super::Roller = String::from("2d6:base+1d6:stress").parse().unwrap();
+  assert_eq!(r.groups, vec![(1, 6)]);
+  assert_eq!(r.colours, vec!["base", "stress"]);
+  assert_eq!(r.pool_colours(), vec!["base", "base", "stress"]);
+}
+
+#[test]
+fn year_zero() {
+  let r: super::Roller = String::from("3d6+2d6+1d6 yz").parse().unwrap();
+  assert_eq!(r.success_threshold, Some(6));
+  assert_eq!(r.colours, vec!["base", "skill", "gear"]);
+  assert!(r.banes);
+}
+
+#[test]
+fn roll_and_keep() {
+  assert_eq!(
+    super::Roller::roll_and_keep("7k3+2 ex9"),
+    Some(String::from("7d10+2 ex9 max3 ex10 ace"))
+  );
+  assert_eq!(super::Roller::roll_and_keep("3d6"), None);
+
+  let r: super::Roller = String::from("6k3-1").parse().unwrap();
+  assert_eq!(r.dice, 6);
+  assert_eq!(r.sides, 10);
+  assert_eq!(r.modifier, Some(-1));
+  assert_eq!(r.take_max, Some(3));
+  assert_eq!(r.explode_threshold, Some(10));
+  assert!(r.ace);
+}
+
+#[test]
+fn ten_dice_rule() {
+  assert_eq!(super::Roller::ten_dice_rule(8, 4), (8, 4, 0));
+  assert_eq!(super::Roller::ten_dice_rule(13, 4), (10, 5, 0));
+  assert_eq!(super::Roller::ten_dice_rule(30, 12), (10, 10, 24));
+  assert_eq!(super::Roller::ten_dice_rule(3, 5), (3, 3, 0));
+  assert_eq!(
+    super::Roller::roll_and_keep("12k4").unwrap(),
+    "10d10 max5 ex10 ace"
+  );
+}
+
+#[test]
+fn bands() {
+  let r: super::Roller = String::from("2d6+2 bands:pbta").parse().unwrap();
+  assert_eq!(r.bands, super::Band::preset("pbta").unwrap());
+
+  let r: Result<super::Roller, super::RollerErr> = String::from("2d6 bands:nope").parse();
+  assert_eq!(
+    r.unwrap_err(),
+    super::RollerErr::UnknownBands(String::from("nope"))
+  );
+
+  let mut bands = std::collections::HashMap::new();
+  bands.insert(String::from("nope"), vec![super::Band::new(1, 6, "low")]);
+  let r = super::Roller::parse_with_bands("2d6 bands:nope", &bands).unwrap();
+  assert_eq!(r.bands, vec![super::Band::new(1, 6, "low")]);
+}
+
+#[test]
+fn band_notation() {
+  let band: super::Band = "10+: strong hit".parse().unwrap();
+  assert_eq!(band, super::Band::new(10, i16::MAX, "strong hit"));
+  let band: super::Band = "6-: miss".parse().unwrap();
+  assert_eq!(band, super::Band::new(i16::MIN, 6, "miss"));
+  let band: super::Band = "7-9: weak hit".parse().unwrap();
+  assert_eq!(band, super::Band::new(7, 9, "weak hit"));
+  let band: super::Band = "-2-0: fail".parse().unwrap();
+  assert_eq!(band, super::Band::new(-2, 0, "fail"));
+  let band: super::Band = "12: boxcars".parse().unwrap();
+  assert_eq!(band, super::Band::new(12, 12, "boxcars"));
+  assert!("strong hit".parse::<super::Band>().is_err());
+}
+
+#[test]
+fn roll_under() {
+  let r: super::Roller = String::from("1d100 under45 crit:brp").parse().unwrap();
+  assert_eq!(r.target, Some(45));
+  assert!(r.roll_under);
+  assert_eq!(r.criticals, Some(super::Criticals::brp()));
+
+  let r: Result<super::Roller, super::RollerErr> = String::from("3d6 under12 crit:nope").parse();
+  assert_eq!(r, Err(super::RollerErr::Generic));
 }
@@ -21,6 +21,14 @@ fn single_die() {
   }
 }
 
+#[test]
+fn wide_die() {
+  let mut r: super::Roller = String::from("1d1000").parse().unwrap();
+  let outcomes: Vec<i16> = (1..=1000).map(|_| r.roll().outcome).collect();
+  assert!(outcomes.iter().all(|x| (1..=1000).contains(x)));
+  assert!(outcomes.iter().any(|x| *x > 255));
+}
+
 #[test]
 fn standard_with_modifier() {
   let mut r: super::Roller = String::from("1d20+20").parse().unwrap();
@@ -104,7 +112,7 @@ fn max_x_of_y() {
     let mut results = roll_result.dice.clone();
     results.sort();
     results.reverse();
-    let max3: i16 = results.iter().take(3).sum::<u8>() as i16;
+    let max3: i16 = results.iter().take(3).sum::<u16>() as i16;
     assert_eq!(max3, roll_result.outcome);
   }
 }
@@ -117,7 +125,7 @@ fn max_x_of_y_exploding() {
     let mut results = roll_result.dice.clone();
     results.sort();
     results.reverse();
-    let max3: i16 = results.iter().take(3).sum::<u8>() as i16;
+    let max3: i16 = results.iter().take(3).sum::<u16>() as i16;
     assert_eq!(max3, roll_result.outcome);
     if roll_result.dice.len() == 6 {
       assert_eq!(results[0], 6);
@@ -133,7 +141,7 @@ fn min_x_of_y() {
     assert_eq!(roll_result.dice.len(), 6);
     let mut results = roll_result.dice.clone();
     results.sort();
-    let min3: i16 = results.iter().take(3).sum::<u8>() as i16;
+    let min3: i16 = results.iter().take(3).sum::<u16>() as i16;
     assert_eq!(min3, roll_result.outcome);
   }
 }
@@ -145,7 +153,7 @@ fn min_x_of_y_exploding() {
     let roll_result = r.roll();
     let mut results = roll_result.dice.clone();
     results.sort();
-    let min3: i16 = results.iter().take(3).sum::<u8>() as i16;
+    let min3: i16 = results.iter().take(3).sum::<u16>() as i16;
     assert_eq!(min3, roll_result.outcome);
     if roll_result.dice.len() == 6 {
       assert_eq!(results[5], 6);
@@ -161,7 +169,7 @@ fn min_x_of_y_with_fewer_dice() {
     assert_eq!(roll_result.dice.len(), 2);
     let mut results = roll_result.dice.clone();
     results.sort();
-    let min3: i16 = results.iter().take(3).sum::<u8>() as i16;
+    let min3: i16 = results.iter().take(3).sum::<u16>() as i16;
     assert_eq!(min3, roll_result.outcome);
   }
 }
@@ -175,7 +183,7 @@ fn max_x_of_y_with_fewer_dice() {
     let mut results = roll_result.dice.clone();
     results.sort();
     results.reverse();
-    let max3: i16 = results.iter().take(3).sum::<u8>() as i16;
+    let max3: i16 = results.iter().take(3).sum::<u16>() as i16;
     assert_eq!(max3, roll_result.outcome);
   }
 }
@@ -188,7 +196,7 @@ fn mid_x_of_y() {
     assert_eq!(roll_result.dice.len(), 7);
     let mut results = roll_result.dice.clone();
     results.sort();
-    let mid3: i16 = results.iter().skip(2).take(3).sum::<u8>() as i16;
+    let mid3: i16 = results.iter().skip(2).take(3).sum::<u16>() as i16;
     assert_eq!(mid3, roll_result.outcome);
   }
 }
@@ -262,3 +270,393 @@ fn failure_values() {
   let roll_result = r.roll();
   assert_eq!(roll_result.outcome, -2);
 }
+
+#[test]
+fn seeded() {
+  let r1: super::Roller = String::from("10d6 ex6").parse().unwrap();
+  let r2: super::Roller = String::from("10d6 ex6").parse().unwrap();
+  let mut r1 = r1.seed(42);
+  let mut r2 = r2.seed(42);
+  for _ in 1..=1000 {
+    assert_eq!(r1.roll().dice, r2.roll().dice);
+  }
+}
+
+#[test]
+fn bands() {
+  let mut r = super::Roller::new(2, 6).bands(vec![
+    super::Band::new(10, 12, "strong hit"),
+    super::Band::new(7, 9, "weak hit"),
+    super::Band::new(2, 6, "miss"),
+  ]);
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let band = match roll_result.outcome {
+      10..=12 => "strong hit",
+      7..=9 => "weak hit",
+      _ => "miss",
+    };
+    assert_eq!(roll_result.band.as_deref(), Some(band));
+  }
+}
+
+#[test]
+fn fallback() {
+  let mut r = super::Roller::new(0, 6).fallback(1, super::Roller::new(2, 6).take_min(Some(1)));
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.dice.len(), 2);
+    assert_eq!(
+      roll_result.outcome,
+      *roll_result.dice.iter().min().unwrap() as i16
+    );
+  }
+}
+
+#[test]
+fn rote() {
+  let mut r: super::Roller = String::from("5d10 sc8 rote").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let failures = roll_result.dice.iter().take(5).filter(|x| **x < 8).count();
+    assert_eq!(roll_result.dice.len(), 5 + failures);
+    let successes = roll_result.dice.iter().filter(|x| **x >= 8).count();
+    assert_eq!(roll_result.outcome, successes as i16);
+  }
+}
+
+#[test]
+fn storyteller() {
+  let mut r: super::Roller = String::from("5d10 wod").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let successes = roll_result.dice.iter().filter(|x| **x >= 8).count() as i16;
+    let tens = roll_result.dice.iter().filter(|x| **x == 10).count();
+    assert_eq!(roll_result.dice.len(), 5 + tens);
+    assert_eq!(roll_result.outcome, successes);
+    assert_eq!(
+      roll_result
+        .flags
+        .contains(&super::super::RollFlag::ExceptionalSuccess),
+      successes >= 5
+    );
+  }
+}
+
+#[test]
+fn chance_die() {
+  let mut r: super::Roller = String::from("0d10 wod").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.dice.len(), 1);
+    let die = roll_result.dice[0];
+    assert_eq!(roll_result.outcome, if die == 10 { 1 } else { 0 });
+    assert_eq!(
+      roll_result
+        .flags
+        .contains(&super::super::RollFlag::DramaticFailure),
+      die == 1
+    );
+  }
+}
+
+#[test]
+fn ace() {
+  let mut r: super::Roller = String::from("3d4 ace").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.dice.len(), 3);
+    assert!(roll_result.dice.iter().all(|x| *x % 4 != 0));
+  }
+}
+
+#[test]
+fn kept_modifier() {
+  // the modifier is left out when keeping plain dice, kept with acing ones
+  let mut r: super::Roller = String::from("4d6+2 max3").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let mut results = roll_result.dice.clone();
+    results.sort();
+    results.reverse();
+    let max3: i16 = results.iter().take(3).sum::<u16>() as i16;
+    assert_eq!(max3, roll_result.outcome);
+  }
+
+  let mut r: super::Roller = String::from("3d10+2 max2 ace").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let mut results = roll_result.dice.clone();
+    results.sort();
+    results.reverse();
+    let max2: i16 = results.iter().take(2).map(|x| *x as i16).sum();
+    assert_eq!(max2 + 2, roll_result.outcome);
+  }
+}
+
+#[test]
+fn savage() {
+  let mut r: super::Roller = String::from("trait d8+1 wild d6").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.sides, vec![8, 6]);
+    let kept = *roll_result.dice.iter().max().unwrap() as i16;
+    assert_eq!(roll_result.outcome, kept + 1);
+    match roll_result.outcome {
+      ..=3 => assert_eq!(roll_result.raises(), None),
+      outcome => assert_eq!(roll_result.raises(), Some((outcome - 4) / 4)),
+    }
+    assert_eq!(
+      roll_result
+        .flags
+        .contains(&super::super::RollFlag::CriticalFailure),
+      roll_result.dice == vec![1, 1]
+    );
+  }
+}
+
+#[test]
+fn effect_selection() {
+  let dice = [7, 1, 5, 3];
+  let sides = [8, 10, 6, 12];
+  assert_eq!(
+    super::super::EffectStrategy::BestTotal.select(&dice, &sides),
+    (12, 12)
+  );
+  assert_eq!(
+    super::super::EffectStrategy::BestEffect.select(&dice, &sides),
+    (12, 12)
+  );
+
+  let dice = [7, 1, 5, 9];
+  let sides = [8, 10, 6, 12];
+  assert_eq!(
+    super::super::EffectStrategy::BestTotal.select(&dice, &sides),
+    (16, 6)
+  );
+  assert_eq!(
+    super::super::EffectStrategy::BestEffect.select(&dice, &sides),
+    (12, 12)
+  );
+
+  let dice = [1, 1];
+  let sides = [8, 6];
+  assert_eq!(
+    super::super::EffectStrategy::BestTotal.select(&dice, &sides),
+    (0, 4)
+  );
+}
+
+#[test]
+fn cortex() {
+  let mut r: super::Roller = String::from("d8+d10+2d6 effect").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.sides, vec![8, 10, 6, 6]);
+    assert!(roll_result.effect.is_some());
+    let hitches = roll_result.dice.iter().filter(|x| **x == 1).count();
+    let mut kept: Vec<u16> = roll_result
+      .dice
+      .iter()
+      .filter(|x| **x != 1)
+      .copied()
+      .collect();
+    kept.sort();
+    let total: i16 = kept.iter().rev().take(2).map(|x| *x as i16).sum();
+    assert_eq!(roll_result.outcome, total);
+    match hitches {
+      0 => assert!(roll_result.flags.is_empty()),
+      4 => assert_eq!(roll_result.flags, vec![super::super::RollFlag::Botch]),
+      _ => assert_eq!(
+        roll_result.flags,
+        vec![super::super::RollFlag::Hitches(hitches)]
+      ),
+    }
+  }
+}
+
+#[test]
+fn reroll() {
+  let mut r: super::Roller = String::from("6d6").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let rerolled = r.reroll(&roll_result, |x| x < 4);
+    assert_eq!(rerolled.dice.len(), 6);
+    roll_result
+      .dice
+      .iter()
+      .zip(rerolled.dice.iter())
+      .filter(|(x, _)| **x >= 4)
+      .for_each(|(x, y)| assert_eq!(x, y));
+    assert_eq!(
+      rerolled.outcome,
+      rerolled.dice.iter().map(|x| *x as i16).sum::<i16>()
+    );
+  }
+}
+
+#[test]
+fn year_zero_push() {
+  let mut r: super::Roller = String::from("3d6+2d6+1d6 yz").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.colours.len(), 6);
+    let pushed = r.push(&roll_result);
+    assert_eq!(pushed.colours, roll_result.colours);
+    roll_result
+      .dice
+      .iter()
+      .zip(pushed.dice.iter())
+      .filter(|(x, _)| **x == 6 || **x == 1)
+      .for_each(|(x, y)| assert_eq!(x, y));
+    let successes = pushed.dice.iter().filter(|x| **x == 6).count() as i16;
+    assert_eq!(pushed.outcome, successes);
+    assert!(pushed.flags.contains(&super::super::RollFlag::Pushed));
+    let banes = pushed
+      .dice
+      .iter()
+      .zip(pushed.colours.iter())
+      .filter(|(x, colour)| **x == 1 && *colour != "skill")
+      .count();
+    assert_eq!(
+      pushed.flags.contains(&super::super::RollFlag::Banes(banes)),
+      banes > 0
+    );
+  }
+}
+
+#[test]
+fn roll_and_keep() {
+  let mut r: super::Roller = String::from("12k4+1").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.dice.len(), 10);
+    let mut dice = roll_result.dice.clone();
+    dice.sort();
+    let kept: i16 = dice.iter().rev().take(5).map(|x| *x as i16).sum();
+    assert_eq!(roll_result.outcome, kept + 1);
+  }
+}
+
+#[test]
+fn pbta() {
+  let mut r: super::Roller = String::from("2d6+1 bands:pbta").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let expected = match roll_result.outcome {
+      10.. => "strong hit",
+      7..=9 => "weak hit",
+      _ => "miss",
+    };
+    assert_eq!(roll_result.band.as_deref(), Some(expected));
+  }
+}
+
+#[test]
+fn criticals() {
+  let gurps = super::super::Criticals::gurps();
+  assert_eq!(
+    gurps.check(12, 4),
+    Some(super::super::RollFlag::CriticalSuccess)
+  );
+  assert_eq!(gurps.check(12, 5), None);
+  assert_eq!(
+    gurps.check(15, 5),
+    Some(super::super::RollFlag::CriticalSuccess)
+  );
+  assert_eq!(
+    gurps.check(16, 6),
+    Some(super::super::RollFlag::CriticalSuccess)
+  );
+  assert_eq!(
+    gurps.check(15, 17),
+    Some(super::super::RollFlag::CriticalFailure)
+  );
+  assert_eq!(gurps.check(16, 17), None);
+  assert_eq!(
+    gurps.check(5, 15),
+    Some(super::super::RollFlag::CriticalFailure)
+  );
+
+  let brp = super::super::Criticals::brp();
+  assert_eq!(
+    brp.check(40, 97),
+    Some(super::super::RollFlag::CriticalFailure)
+  );
+  assert_eq!(brp.check(60, 97), None);
+}
+
+#[test]
+fn gurps_automatic() {
+  let gurps = super::super::Criticals::gurps();
+  assert_eq!(gurps.automatic(4), Some(true));
+  assert_eq!(gurps.automatic(10), None);
+  assert_eq!(gurps.automatic(17), Some(false));
+
+  let failure = super::super::RollResult::new(vec![6, 6, 5], 17)
+    .target(Some(18))
+    .roll_under(true)
+    .automatic(Some(false));
+  assert_eq!(failure.to_string(), "[6, 6, 5] => 17 => automatic failure");
+  let success = super::super::RollResult::new(vec![1, 1, 2], 4)
+    .target(Some(2))
+    .roll_under(true)
+    .automatic(Some(true));
+  assert_eq!(success.to_string(), "[1, 1, 2] => 4 => automatic success");
+}
+#[test]
+fn traveller_effect() {
+  let mut r: super::Roller = String::from("2d6+1 tn8").parse().unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    assert_eq!(roll_result.margin(), Some(roll_result.outcome - 8));
+  }
+}
+
+#[test]
+fn success_rules() {
+  let mut r: super::Roller = String::from("6d10 sc7 rule:=10:3:m2 rule:>=9:2 rule:<=1:-1:t1")
+    .parse()
+    .unwrap();
+  for _ in 1..=1000 {
+    let roll_result = r.roll();
+    let tens = roll_result.dice.iter().filter(|x| **x == 10).count();
+    let plain = roll_result.dice.iter().filter(|x| **x >= 7).count();
+    let expected: i16 = roll_result
+      .dice
+      .iter()
+      .map(|x| match x {
+        10 if tens >= 2 => 3,
+        9..=10 => 2,
+        7..=8 => 1,
+        1 if plain >= 1 => -1,
+        _ => 0,
+      })
+      .sum();
+    assert_eq!(roll_result.outcome, expected);
+  }
+}
+
+#[test]
+fn explode_over_largest_face() {
+  let mut r: super::Roller = String::from("3d6 ex256").parse().unwrap();
+  for _ in 1..=1000 {
+    assert_eq!(r.roll().dice.len(), 3);
+  }
+}
+
+#[test]
+fn bounded_explosions() {
+  let mut r = super::Roller::new(1, 1).ace(true);
+  assert_eq!(r.roll().outcome, 101);
+}
+
+#[test]
+fn large_pools() {
+  let mut r: super::Roller = String::from("200d6 sc1").parse().unwrap();
+  assert_eq!(r.roll().outcome, 200);
+
+  let mut r: super::Roller = String::from("2d6 mid3").parse().unwrap();
+  let result = r.roll();
+  assert_eq!(result.outcome, result.dice.iter().sum::<u16>() as i16);
+}
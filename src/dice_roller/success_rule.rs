@@ -0,0 +1,126 @@
+use super::RollerErr;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// How a die is compared to the face of a rule
+pub enum Comparison {
+  Equal,
+  AtLeast,
+  AtMost,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// When a rule applies, on top of its comparison
+pub enum Condition {
+  Always,
+
+  /// the die meets the success threshold
+  Success,
+
+  /// the die misses the success threshold
+  Failure,
+
+  /// at least as many dice show the same face, such as a pair
+  Matching(usize),
+
+  /// the roll counts at least as many plain successes, one per die meeting the threshold
+  Successes(i16),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The value in successes of the dice matching the comparison and the condition. Rules are
+/// checked in order and the first one applying sets the value
+pub struct SuccessRule {
+  pub comparison: Comparison,
+  pub face: u16,
+  pub value: i8,
+  pub condition: Condition,
+}
+
+impl SuccessRule {
+  pub fn new(comparison: Comparison, face: u16, value: i8) -> Self {
+    Self {
+      comparison,
+      face,
+      value,
+      condition: Condition::Always,
+    }
+  }
+
+  pub fn condition(mut self, condition: Condition) -> Self {
+    self.condition = condition;
+    self
+  }
+
+  /// Whether the rule applies to the die, given the success threshold and the whole roll
+  pub fn applies(&self, die: u16, success_threshold: u32, dice: &[u16]) -> bool {
+    let compared = match self.comparison {
+      Comparison::Equal => die == self.face,
+      Comparison::AtLeast => die >= self.face,
+      Comparison::AtMost => die <= self.face,
+    };
+
+    let success = die as u32 >= success_threshold;
+    let conditioned = match self.condition {
+      Condition::Always => true,
+      Condition::Success => success,
+      Condition::Failure => !success,
+      Condition::Matching(count) => dice.iter().filter(|x| **x == die).count() >= count,
+      Condition::Successes(count) => {
+        let successes = dice
+          .iter()
+          .filter(|x| **x as u32 >= success_threshold)
+          .count();
+        successes as i16 >= count
+      }
+    };
+
+    compared && conditioned
+  }
+}
+
+/// ">=9:2", "=10:2:m2" for pairs of 10s, "<=2:-1:f", "=10:3:t5" with 5 successes or more
+impl FromStr for SuccessRule {
+  type Err = RollerErr;
+
+  fn from_str(descriptor: &str) -> Result<SuccessRule, RollerErr> {
+    let tokens: Vec<&str> = descriptor.split(':').collect();
+    let (face, value, condition) = match tokens.as_slice() {
+      [face, value] => (*face, *value, None),
+      [face, value, condition] => (*face, *value, Some(*condition)),
+      _ => return Err(RollerErr::Generic),
+    };
+
+    let (comparison, face) = if let Some(face) = face.strip_prefix(">=") {
+      (Comparison::AtLeast, face)
+    } else if let Some(face) = face.strip_prefix("<=") {
+      (Comparison::AtMost, face)
+    } else {
+      (Comparison::Equal, face.trim_start_matches('='))
+    };
+
+    let condition = match condition {
+      None => Condition::Always,
+      Some("s") => Condition::Success,
+      Some("f") => Condition::Failure,
+      Some(condition) => match (condition.get(..1), condition.get(1..)) {
+        (Some("m"), Some(count)) => {
+          Condition::Matching(count.parse().map_err(|_| RollerErr::Generic)?)
+        }
+        (Some("t"), Some(count)) => {
+          Condition::Successes(count.parse().map_err(|_| RollerErr::Generic)?)
+        }
+        _ => return Err(RollerErr::Generic),
+      },
+    };
+
+    Ok(
+      SuccessRule::new(
+        comparison,
+        face.parse().map_err(|_| RollerErr::Generic)?,
+        value.parse().map_err(|_| RollerErr::Generic)?,
+      )
+      .condition(condition),
+    )
+  }
+}
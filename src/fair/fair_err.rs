@@ -0,0 +1,36 @@
+use super::super::RollerErr;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+/// Error returned while making or verifying provably fair rolls
+pub enum FairErr {
+  Io,
+  Parse,
+
+  /// the revealed server seed does not hash to the published commitment
+  CommitmentMismatch,
+
+  /// rolling before the players chose the client seed
+  NoClientSeed,
+
+  /// the client seed was already chosen
+  ClientSeedSet,
+
+  Roller(RollerErr),
+}
+
+impl fmt::Display for FairErr {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      FairErr::Io => write!(f, "the session file cannot be read or written"),
+      FairErr::Parse => write!(f, "the session file is malformed"),
+      FairErr::CommitmentMismatch => write!(f, "the server seed does not match the commitment"),
+      FairErr::NoClientSeed => write!(
+        f,
+        "no client seed yet: the players pick one for `fair seed`"
+      ),
+      FairErr::ClientSeedSet => write!(f, "the client seed is already set"),
+      FairErr::Roller(err) => write!(f, "invalid definition: {:?}", err),
+    }
+  }
+}
@@ -0,0 +1,17 @@
+use super::super::{RollResult, Roller};
+use super::{fair_seed, FairErr, FairSession};
+
+impl FairSession {
+  /// Throws the definition with the seed of the next nonce, returning the nonce used. The players
+  /// must have chosen the client seed
+  pub fn roll(&mut self, definition: &str) -> Result<(u64, RollResult), FairErr> {
+    let client_seed = self.client_seed.as_deref().ok_or(FairErr::NoClientSeed)?;
+    let r: Roller = definition.parse().map_err(FairErr::Roller)?;
+
+    let seed = fair_seed(&self.server_seed, client_seed, self.nonce);
+    let nonce = self.nonce;
+    self.nonce += 1;
+
+    Ok((nonce, r.seed(seed).roll()))
+  }
+}
@@ -0,0 +1,21 @@
+use super::{FairErr, FairSession};
+use std::fs;
+use std::path::Path;
+
+impl FairSession {
+  /// Restores a session saved by a previous run
+  pub fn load(path: &Path) -> Result<FairSession, FairErr> {
+    let content = fs::read_to_string(path).map_err(|_| FairErr::Io)?;
+    serde_json::from_str(&content).map_err(|_| FairErr::Parse)
+  }
+
+  /// Saves the seeds and the nonce. The file holds the secret server seed
+  pub fn save(&self, path: &Path) -> Result<(), FairErr> {
+    if let Some(directory) = path.parent() {
+      fs::create_dir_all(directory).map_err(|_| FairErr::Io)?;
+    }
+
+    let content = serde_json::to_string(self).map_err(|_| FairErr::Parse)?;
+    fs::write(path, content).map_err(|_| FairErr::Io)
+  }
+}
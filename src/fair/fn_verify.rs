@@ -0,0 +1,40 @@
+use super::super::{RollResult, Roller};
+use super::FairErr;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+/// The SHA-256 of the server seed, in hex
+pub fn commitment(server_seed: &str) -> String {
+  hex::encode(Sha256::digest(server_seed.as_bytes()))
+}
+
+/// The seed of the random number generator of a roll: the first 8 bytes of
+/// HMAC-SHA256(server seed, "client seed:nonce"), big endian. Throwing the definition with
+/// `--seed` and this value yields the same roll
+pub fn fair_seed(server_seed: &str, client_seed: &str, nonce: u64) -> u64 {
+  let mut mac =
+    Hmac::<Sha256>::new_from_slice(server_seed.as_bytes()).expect("HMAC takes keys of any length");
+  mac.update(format!("{}:{}", client_seed, nonce).as_bytes());
+  let bytes = mac.finalize().into_bytes();
+
+  let mut seed = [0_u8; 8];
+  seed.copy_from_slice(&bytes[..8]);
+  u64::from_be_bytes(seed)
+}
+
+/// Throws a roll again from the revealed server seed, after checking it against the commitment
+/// published beforehand
+pub fn verify(
+  commitment: &str,
+  server_seed: &str,
+  client_seed: &str,
+  nonce: u64,
+  definition: &str,
+) -> Result<RollResult, FairErr> {
+  if self::commitment(server_seed) != commitment.trim().to_lowercase() {
+    return Err(FairErr::CommitmentMismatch);
+  }
+
+  let r: Roller = definition.parse().map_err(FairErr::Roller)?;
+  Ok(r.seed(fair_seed(server_seed, client_seed, nonce)).roll())
+}
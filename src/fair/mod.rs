@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+// public functions implementation
+mod fn_roll;
+mod fn_state;
+mod fn_verify;
+
+// unit tests
+mod ts_verify;
+
+mod fair_err;
+
+pub use fair_err::*;
+pub use fn_verify::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Provably fair rolls. The SHA-256 of the server seed is published before rolling, then every
+/// roll is seeded by HMAC-SHA256(server seed, "client seed:nonce"). Once the server seed is
+/// revealed, anyone can check it against the commitment and throw every roll again
+pub struct FairSession {
+  /// secret until revealed, 32 random bytes in hex
+  server_seed: String,
+
+  /// chosen by the players once the commitment is published, no roll is made before. The server
+  /// seed being fixed first, it cannot be picked to suit the client seed
+  client_seed: Option<String>,
+
+  /// how many rolls were made, the next one using it
+  nonce: u64,
+}
+
+impl FairSession {
+  /// A session waiting for the client seed
+  pub fn new(server_seed: &str) -> Self {
+    Self {
+      server_seed: String::from(server_seed),
+      client_seed: None,
+      nonce: 0,
+    }
+  }
+
+  /// A session with a random server seed
+  pub fn random() -> Self {
+    let server_seed: [u8; 32] = rand::random();
+    Self::new(&hex::encode(server_seed))
+  }
+
+  /// Sets the seed chosen by the players, once only
+  pub fn accept(&mut self, client_seed: &str) -> Result<(), FairErr> {
+    if self.client_seed.is_some() {
+      return Err(FairErr::ClientSeedSet);
+    }
+
+    self.client_seed = Some(String::from(client_seed));
+    Ok(())
+  }
+
+  /// The commitment to publish before rolling: the SHA-256 of the server seed, in hex
+  pub fn commitment(&self) -> String {
+    commitment(&self.server_seed)
+  }
+
+  /// The secret server seed, to be revealed once the rolls are done
+  pub fn server_seed(&self) -> &str {
+    &self.server_seed
+  }
+
+  pub fn client_seed(&self) -> Option<&str> {
+    self.client_seed.as_deref()
+  }
+
+  /// The nonce of the next roll
+  pub fn nonce(&self) -> u64 {
+    self.nonce
+  }
+}
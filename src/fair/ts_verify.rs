@@ -0,0 +1,73 @@
+#[test]
+fn commitment() {
+  // SHA-256 of "abc"
+  assert_eq!(
+    super::commitment("abc"),
+    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+  );
+}
+
+#[test]
+fn fair_seed() {
+  // first 8 bytes of HMAC-SHA256, key "server", message "players:0"
+  assert_eq!(
+    super::fair_seed("server", "players", 0),
+    6_295_261_055_375_393_146
+  );
+  assert_ne!(
+    super::fair_seed("server", "players", 0),
+    super::fair_seed("server", "players", 1)
+  );
+}
+
+#[test]
+fn roll_and_verify() {
+  let mut session = super::FairSession::new("server");
+  let commitment = session.commitment();
+  session.accept("players").unwrap();
+
+  let (nonce, first) = session.roll("4d6 max3").unwrap();
+  let (_, second) = session.roll("1d20+5").unwrap();
+  assert_eq!(nonce, 0);
+  assert_eq!(session.nonce(), 2);
+
+  let verified = super::verify(&commitment, "server", "players", 0, "4d6 max3").unwrap();
+  assert_eq!(verified.dice, first.dice);
+  assert_eq!(verified.outcome, first.outcome);
+
+  let verified = super::verify(&commitment, "server", "players", 1, "1d20+5").unwrap();
+  assert_eq!(verified.outcome, second.outcome);
+}
+
+#[test]
+fn wrong_server_seed() {
+  let session = super::FairSession::new("server");
+  assert_eq!(
+    super::verify(&session.commitment(), "another", "players", 0, "1d20").unwrap_err(),
+    super::FairErr::CommitmentMismatch
+  );
+}
+
+#[test]
+fn random() {
+  let session = super::FairSession::random();
+  assert_eq!(session.server_seed().len(), 64);
+  assert_eq!(session.commitment().len(), 64);
+}
+
+#[test]
+fn client_seed_after_commitment() {
+  let mut session = super::FairSession::new("server");
+  assert_eq!(
+    session.roll("1d20").unwrap_err(),
+    super::FairErr::NoClientSeed
+  );
+  assert_eq!(session.nonce(), 0);
+
+  session.accept("players").unwrap();
+  assert_eq!(
+    session.accept("another").unwrap_err(),
+    super::FairErr::ClientSeedSet
+  );
+  assert_eq!(session.client_seed(), Some("players"));
+}
@@ -0,0 +1,21 @@
+use super::{Initiative, InitiativeErr};
+use std::fs;
+use std::path::Path;
+
+impl Initiative {
+  /// Restores an initiative order saved by a previous run
+  pub fn load(path: &Path) -> Result<Initiative, InitiativeErr> {
+    let content = fs::read_to_string(path).map_err(|_| InitiativeErr::Io)?;
+    serde_json::from_str(&content).map_err(|_| InitiativeErr::Parse)
+  }
+
+  /// Saves the order, the turn, the round and the action deck
+  pub fn save(&self, path: &Path) -> Result<(), InitiativeErr> {
+    if let Some(directory) = path.parent() {
+      fs::create_dir_all(directory).map_err(|_| InitiativeErr::Io)?;
+    }
+
+    let content = serde_json::to_string(self).map_err(|_| InitiativeErr::Parse)?;
+    fs::write(path, content).map_err(|_| InitiativeErr::Io)
+  }
+}
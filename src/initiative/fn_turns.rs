@@ -0,0 +1,156 @@
+use super::super::Roller;
+use super::{Combatant, Initiative, InitiativeErr, CARDS};
+use rand::Rng;
+use std::cmp::Reverse;
+
+impl Initiative {
+  /// Rolls initiative for a newcomer, "1d20+2" or "cards", and puts them in order, ahead of
+  /// anyone scoring lower. Once the fight has started, the combatant acting keeps the turn
+  pub fn add(&mut self, name: &str, definition: &str) -> Result<(), InitiativeErr> {
+    if self.position(name).is_ok() {
+      return Err(InitiativeErr::DuplicateCombatant(String::from(name)));
+    }
+
+    let combatant = self.roll(name, definition)?;
+    let position = self
+      .combatants
+      .iter()
+      .position(|x| order(x) > order(&combatant))
+      .unwrap_or(self.combatants.len());
+    self.combatants.insert(position, combatant);
+
+    match self.started {
+      true if position <= self.turn => self.turn += 1,
+      true => (),
+      false => self.turn = 0,
+    }
+
+    Ok(())
+  }
+
+  /// Takes a combatant out of the fight. Removing the one acting passes the turn on
+  pub fn remove(&mut self, name: &str) -> Result<Combatant, InitiativeErr> {
+    let position = self.position(name)?;
+    let combatant = self.combatants.remove(position);
+
+    if position < self.turn {
+      self.turn -= 1;
+    }
+    if self.turn >= self.combatants.len() {
+      self.turn = 0;
+    }
+
+    Ok(combatant)
+  }
+
+  /// Moves a combatant after the next one. Delaying the one acting passes the turn on
+  pub fn delay(&mut self, name: &str) -> Result<(), InitiativeErr> {
+    let position = self.position(name)?;
+    let current = self.current().map(|x| x.name.clone());
+
+    if position + 1 < self.combatants.len() {
+      self.combatants.swap(position, position + 1);
+    }
+    if position != self.turn {
+      self.turn = current.and_then(|x| self.position(&x).ok()).unwrap_or(0);
+    }
+
+    Ok(())
+  }
+
+  /// Passes the turn to the next combatant. After the last one a new round starts, dealing
+  /// action cards again
+  pub fn pass(&mut self) -> Result<Option<&Combatant>, InitiativeErr> {
+    self.started = true;
+    self.turn += 1;
+
+    if self.turn >= self.combatants.len() {
+      self.turn = 0;
+      self.round += 1;
+      self.deal()?;
+    }
+
+    Ok(self.current())
+  }
+
+  /// deals a new action card to everyone drawing cards, after discarding the old ones
+  fn deal(&mut self) -> Result<(), InitiativeErr> {
+    if !self.combatants.iter().any(|x| x.definition == CARDS) {
+      return Ok(());
+    }
+
+    self.deck.discard();
+    for position in 0..self.combatants.len() {
+      if self.combatants[position].definition == CARDS {
+        let name = self.combatants[position].name.clone();
+        self.combatants[position] = self.roll(&name, CARDS)?;
+      }
+    }
+
+    self.sort();
+    Ok(())
+  }
+
+  fn roll(&mut self, name: &str, definition: &str) -> Result<Combatant, InitiativeErr> {
+    let roll_off = self.rng.gen();
+
+    if definition == CARDS {
+      // with every card in the pile, as in a new deck, shuffling loses nothing
+      if self.deck.drawn().is_empty() && self.deck.discarded() == 0 {
+        self.deck.shuffle();
+      }
+
+      let card = self
+        .deck
+        .draw(1)
+        .map_err(|_| InitiativeErr::Deck)?
+        .cards
+        .pop()
+        .ok_or(InitiativeErr::Deck)?;
+
+      return Ok(Combatant {
+        name: String::from(name),
+        definition: String::from(definition),
+        score: card.rank as i16,
+        bonus: 0,
+        roll_off,
+        card: Some(card),
+      });
+    }
+
+    let result = definition
+      .parse::<Roller>()
+      .map_err(|_| InitiativeErr::InvalidDefinition(String::from(definition)))?
+      .seed(self.rng.gen())
+      .roll();
+    let dice: i16 = result.dice.iter().map(|x| *x as i16).sum();
+
+    Ok(Combatant {
+      name: String::from(name),
+      definition: String::from(definition),
+      score: result.outcome,
+      bonus: result.outcome - dice,
+      roll_off,
+      card: None,
+    })
+  }
+
+  /// puts everyone in order again, the top one acting
+  fn sort(&mut self) {
+    self.combatants.sort_by_key(order);
+    self.turn = 0;
+  }
+
+  fn position(&self, name: &str) -> Result<usize, InitiativeErr> {
+    self
+      .combatants
+      .iter()
+      .position(|x| x.name == name)
+      .ok_or(InitiativeErr::UnknownCombatant(String::from(name)))
+  }
+}
+
+/// highest score first, then highest bonus, then highest roll off
+fn order(combatant: &Combatant) -> Reverse<(i16, i16, u8)> {
+  Reverse((combatant.score, combatant.bonus, combatant.roll_off))
+}
@@ -0,0 +1,23 @@
+use super::Initiative;
+use std::fmt;
+
+/// "round 2" followed by the order of play, the one acting marked with ">"
+impl fmt::Display for Initiative {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "round {}", self.round)?;
+
+    for (position, combatant) in self.combatants.iter().enumerate() {
+      let marker = match position == self.turn {
+        true => ">",
+        false => " ",
+      };
+      let score = match &combatant.card {
+        Some(card) => card.label.clone(),
+        None => combatant.score.to_string(),
+      };
+      write!(f, "\n{} {} {}", marker, combatant.name, score)?;
+    }
+
+    Ok(())
+  }
+}
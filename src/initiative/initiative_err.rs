@@ -0,0 +1,10 @@
+#[derive(Debug, PartialEq)]
+/// Error returned while tracking initiative or saving its state
+pub enum InitiativeErr {
+  Io,
+  Parse,
+  InvalidDefinition(String),
+  UnknownCombatant(String),
+  DuplicateCombatant(String),
+  Deck,
+}
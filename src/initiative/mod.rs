@@ -0,0 +1,103 @@
+use super::{Card, Deck};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+// public functions implementation
+mod fn_state;
+mod fn_turns;
+mod impl_display;
+
+// unit tests
+mod ts_turns;
+
+mod initiative_err;
+
+pub use initiative_err::*;
+
+/// the definition dealing a Savage Worlds action card instead of rolling
+const CARDS: &str = "cards";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Someone taking part in the fight
+pub struct Combatant {
+  pub name: String,
+
+  /// the initiative definition, "1d20+2", or "cards" for a Savage Worlds action card
+  pub definition: String,
+
+  /// the rolled outcome, or the rank of the card
+  pub score: i16,
+
+  /// what the definition adds to the dice, first tie breaker
+  pub bonus: i16,
+
+  /// random number breaking the remaining ties
+  pub roll_off: u8,
+
+  /// the action card dealt, if any
+  pub card: Option<Card>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// An initiative order, stepping through turns and rounds. Action cards are dealt again every
+/// round, while rolled scores are kept
+pub struct Initiative {
+  /// the order of play, the first one acting first
+  combatants: Vec<Combatant>,
+
+  /// the position of the combatant acting
+  turn: usize,
+
+  round: u32,
+
+  /// whether a turn has been passed, before that the top of the order acts
+  started: bool,
+
+  /// the action deck, jokers included
+  deck: Deck,
+
+  /// random number generator, seeding every initiative roll
+  #[serde(skip, default = "StdRng::from_entropy")]
+  rng: StdRng,
+}
+
+impl Default for Initiative {
+  fn default() -> Self {
+    Self {
+      combatants: Vec::new(),
+      turn: 0,
+      round: 1,
+      started: false,
+      deck: Deck::standard(true),
+      rng: StdRng::from_entropy(),
+    }
+  }
+}
+
+impl Initiative {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Seeds the random number generators, making rolls and deals reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self.deck = self.deck.seed(seed.wrapping_add(1));
+    self
+  }
+
+  /// The order of play
+  pub fn combatants(&self) -> &[Combatant] {
+    &self.combatants
+  }
+
+  /// The combatant acting, if any
+  pub fn current(&self) -> Option<&Combatant> {
+    self.combatants.get(self.turn)
+  }
+
+  pub fn round(&self) -> u32 {
+    self.round
+  }
+}
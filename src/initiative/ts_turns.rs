@@ -0,0 +1,89 @@
+#[test]
+fn sorted_by_score() {
+  let mut i = super::Initiative::new().seed(1);
+  i.add("goblin", "1d20").unwrap();
+  i.add("fighter", "1d20+2").unwrap();
+  i.add("wizard", "1d20-1").unwrap();
+
+  let scores: Vec<i16> = i.combatants().iter().map(|x| x.score).collect();
+  let mut sorted = scores.clone();
+  sorted.sort_by(|a, b| b.cmp(a));
+  assert_eq!(scores, sorted);
+  assert_eq!(i.combatants()[0].name, i.current().unwrap().name);
+}
+
+#[test]
+fn tie_broken_by_bonus() {
+  let mut i = super::Initiative::new().seed(1);
+  i.add("goblin", "1d1+4").unwrap();
+  i.add("fighter", "1d1+4").unwrap();
+  i.add("wizard", "2d1+3").unwrap();
+
+  assert_eq!(i.combatants()[2].name, "wizard");
+  assert_eq!(i.combatants()[0].bonus, 4);
+}
+
+#[test]
+fn cards() {
+  let mut i = super::Initiative::new().seed(1);
+  i.add("hero", "cards").unwrap();
+  i.add("villain", "cards").unwrap();
+
+  let first = &i.combatants()[0];
+  assert!(first.card.is_some());
+  assert!(first.score > i.combatants()[1].score);
+}
+
+#[test]
+fn turns_and_rounds() {
+  let mut i = super::Initiative::new().seed(1);
+  i.add("a", "1d1+3").unwrap();
+  i.add("b", "1d1+2").unwrap();
+
+  assert_eq!(i.current().unwrap().name, "a");
+  assert_eq!(i.pass().unwrap().unwrap().name, "b");
+  assert_eq!(i.pass().unwrap().unwrap().name, "a");
+  assert_eq!(i.round(), 2);
+}
+
+#[test]
+fn remove_and_delay() {
+  let mut i = super::Initiative::new().seed(1);
+  i.add("a", "1d1+3").unwrap();
+  i.add("b", "1d1+2").unwrap();
+  i.add("c", "1d1+1").unwrap();
+
+  i.delay("a").unwrap();
+  assert_eq!(i.current().unwrap().name, "b");
+  assert_eq!(i.combatants()[1].name, "a");
+
+  i.delay("c").unwrap();
+  assert_eq!(i.current().unwrap().name, "b");
+
+  i.add("d", "1d1+9").unwrap();
+  assert_eq!(i.current().unwrap().name, "d");
+  i.pass().unwrap();
+  i.add("e", "1d1+9").unwrap();
+  assert_eq!(i.current().unwrap().name, "b");
+
+  i.remove("b").unwrap();
+  assert_eq!(i.current().unwrap().name, "a");
+  assert_eq!(
+    i.remove("z").unwrap_err(),
+    super::InitiativeErr::UnknownCombatant(String::from("z"))
+  );
+}
+
+#[test]
+fn errors() {
+  let mut i = super::Initiative::new();
+  i.add("a", "1d20").unwrap();
+  assert_eq!(
+    i.add("a", "1d20").unwrap_err(),
+    super::InitiativeErr::DuplicateCombatant(String::from("a"))
+  );
+  assert_eq!(
+    i.add("b", "fast").unwrap_err(),
+    super::InitiativeErr::InvalidDefinition(String::from("fast"))
+  );
+}
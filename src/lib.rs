@@ -1,3 +1,29 @@
+mod api;
+mod character;
+mod config;
 mod dice_roller;
+mod fair;
+mod initiative;
+mod luck;
+mod oracle;
+mod roll_log;
+mod room;
+mod selftest;
+mod statistics;
+mod systems;
+mod table;
 
+pub use api::*;
+pub use character::*;
+pub use config::*;
 pub use dice_roller::*;
+pub use fair::*;
+pub use initiative::*;
+pub use luck::*;
+pub use oracle::*;
+pub use roll_log::*;
+pub use room::*;
+pub use selftest::*;
+pub use statistics::*;
+pub use systems::*;
+pub use table::*;
@@ -0,0 +1,105 @@
+use super::super::{chi_square, z_score, Band, Contest, Distribution, LogEntry, Roller};
+use super::{DefinitionLuck, DieLuck, LuckReport};
+use std::collections::{BTreeMap, HashMap};
+
+impl LuckReport {
+  /// Compares the logged rolls, in the order they were made, to the exact distribution of their
+  /// definition. Band tables are those a definition may name
+  pub fn new(entries: &[LogEntry], bands: &HashMap<String, Vec<Band>>) -> Self {
+    let mut report = LuckReport::default();
+
+    // definitions in order of first appearance
+    let mut definitions: Vec<&str> = Vec::new();
+    for entry in entries {
+      if !definitions.contains(&entry.resolved.as_str()) {
+        definitions.push(&entry.resolved);
+      }
+    }
+
+    let mut faces: BTreeMap<u32, Vec<u64>> = BTreeMap::new();
+    for definition in definitions {
+      let Some(distribution) = distribution(definition, bands) else {
+        report.unsupported.push(String::from(definition));
+        continue;
+      };
+
+      let rolls: Vec<&LogEntry> = entries
+        .iter()
+        .filter(|x| x.resolved == definition)
+        .collect();
+      report
+        .definitions
+        .push(DefinitionLuck::new(definition, &rolls, &distribution));
+
+      // fair dice only, exploded and acing ones have no distribution
+      for entry in rolls {
+        for (face, sides) in entry.dice.iter().zip(entry.sides.iter()) {
+          let counts = faces.entry(*sides).or_insert(vec![0; *sides as usize]);
+          if let Some(count) = counts.get_mut(*face as usize - 1) {
+            *count += 1;
+          }
+        }
+      }
+    }
+
+    report.dice = faces
+      .into_iter()
+      .map(|(sides, faces)| DieLuck {
+        sides,
+        fit: chi_square(&faces, &vec![1.0 / sides as f64; sides as usize]),
+        faces,
+      })
+      .collect();
+
+    report
+  }
+}
+
+impl DefinitionLuck {
+  fn new(definition: &str, rolls: &[&LogEntry], distribution: &Distribution) -> Self {
+    let count = rolls.len() as f64;
+    let expected = distribution.mean();
+    let mean = rolls.iter().map(|x| x.outcome as f64).sum::<f64>() / count;
+    let percentile = rolls
+      .iter()
+      .map(|x| distribution.percentile(x.outcome))
+      .sum::<f64>()
+      / count;
+
+    Self {
+      definition: String::from(definition),
+      rolls: rolls.len(),
+      mean,
+      expected,
+      z_score: z_score(mean, expected, distribution.std_dev() / count.sqrt()),
+      percentile: percentile * 100.0,
+      hot_streak: streak(rolls, |x| x > expected),
+      cold_streak: streak(rolls, |x| x < expected),
+    }
+  }
+}
+
+/// the distribution of a plain roll, opposed rolls have none
+fn distribution(definition: &str, bands: &HashMap<String, Vec<Band>>) -> Option<Distribution> {
+  if Contest::is_contest(definition) {
+    return None;
+  }
+
+  Roller::parse_with_bands(definition, bands)
+    .ok()?
+    .distribution()
+}
+
+/// the longest run of consecutive outcomes meeting the condition
+fn streak(rolls: &[&LogEntry], condition: impl Fn(f64) -> bool) -> usize {
+  let mut longest = 0;
+  let mut current = 0;
+  for roll in rolls {
+    current = match condition(roll.outcome as f64) {
+      true => current + 1,
+      false => 0,
+    };
+    longest = longest.max(current);
+  }
+  longest
+}
@@ -0,0 +1,70 @@
+use super::{DefinitionLuck, DieLuck, LuckReport};
+use std::fmt;
+
+/// "1d20+5: 12 rolls, mean 14.25 (expected 15.50), z -0.87, 41st percentile, hot streak 3, cold streak 4"
+impl fmt::Display for DefinitionLuck {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let percentile = self.percentile.round() as u32;
+    let suffix = match (percentile % 10, percentile % 100) {
+      (_, 11..=13) => "th",
+      (1, _) => "st",
+      (2, _) => "nd",
+      (3, _) => "rd",
+      _ => "th",
+    };
+
+    write!(
+      f,
+      "{}: {} rolls, mean {:.2} (expected {:.2}), z {:.2}, {}{} percentile, hot streak {}, cold streak {}",
+      self.definition,
+      self.rolls,
+      self.mean,
+      self.expected,
+      self.z_score,
+      percentile,
+      suffix,
+      self.hot_streak,
+      self.cold_streak
+    )
+  }
+}
+
+/// "d6: 120 dice [1: 18, 2: 22, 3: 19, 4: 21, 5: 20, 6: 20], chi-square 0.70 (5 df), p 0.98"
+impl fmt::Display for DieLuck {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let faces: Vec<String> = self
+      .faces
+      .iter()
+      .enumerate()
+      .map(|(face, count)| format!("{}: {}", face + 1, count))
+      .collect();
+
+    write!(
+      f,
+      "d{}: {} dice [{}], chi-square {:.2} ({} df), p {:.2}",
+      self.sides,
+      self.faces.iter().sum::<u64>(),
+      faces.join(", "),
+      self.fit.statistic,
+      self.fit.degrees,
+      self.fit.p_value
+    )
+  }
+}
+
+/// one line per definition, then one per die size, then the definitions left out
+impl fmt::Display for LuckReport {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut lines: Vec<String> = Vec::new();
+    lines.extend(self.definitions.iter().map(|x| x.to_string()));
+    lines.extend(self.dice.iter().map(|x| x.to_string()));
+    lines.extend(
+      self
+        .unsupported
+        .iter()
+        .map(|x| format!("{}: no exact distribution", x)),
+    );
+
+    write!(f, "{}", lines.join("\n"))
+  }
+}
@@ -0,0 +1,56 @@
+use super::ChiSquare;
+
+// public functions implementation
+mod fn_report;
+mod impl_display;
+
+// unit tests
+mod ts_report;
+
+#[derive(Debug, Clone, PartialEq)]
+/// How the logged rolls of a definition compare to its exact distribution
+pub struct DefinitionLuck {
+  /// the definition actually thrown
+  pub definition: String,
+
+  pub rolls: usize,
+
+  /// the mean of the observed outcomes
+  pub mean: f64,
+
+  /// the mean of the distribution
+  pub expected: f64,
+
+  /// how many standard errors the observed mean lies from the expected one
+  pub z_score: f64,
+
+  /// the mean percentile of the outcomes, from 0 to 100. 50 is as lucky as can be expected
+  pub percentile: f64,
+
+  /// the longest run of outcomes over the expected mean
+  pub hot_streak: usize,
+
+  /// the longest run of outcomes under the expected mean
+  pub cold_streak: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// How the faces of the logged dice of a size fit a fair die
+pub struct DieLuck {
+  pub sides: u32,
+
+  /// how many dice showed every face, 1 first
+  pub faces: Vec<u64>,
+
+  pub fit: ChiSquare,
+}
+
+#[derive(Debug, Default, PartialEq)]
+/// The luck of logged rolls, per definition and per die size
+pub struct LuckReport {
+  pub definitions: Vec<DefinitionLuck>,
+  pub dice: Vec<DieLuck>,
+
+  /// definitions without an exact distribution, such as exploding dice and opposed rolls
+  pub unsupported: Vec<String>,
+}
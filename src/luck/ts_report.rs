@@ -0,0 +1,52 @@
+#[test]
+fn definitions() {
+  let entries: Vec<super::super::LogEntry> = [20, 18, 2, 15]
+    .into_iter()
+    .map(|x| {
+      let result = super::super::RollResult::new(vec![x], x as i16).sides(vec![20]);
+      super::super::LogEntry::new("1d20", "1d20", 0, 1).roll(&result)
+    })
+    .collect();
+  let report = super::LuckReport::new(&entries, &std::collections::HashMap::new());
+
+  let luck = &report.definitions[0];
+  assert_eq!(luck.rolls, 4);
+  assert!((luck.mean - 13.75).abs() < 1e-9);
+  assert!((luck.expected - 10.5).abs() < 1e-9);
+  assert!((luck.percentile - 66.25).abs() < 1e-9);
+  assert_eq!(luck.hot_streak, 2);
+  assert_eq!(luck.cold_streak, 1);
+  assert!(luck.z_score > 1.0);
+}
+
+#[test]
+fn dice() {
+  let entries: Vec<super::super::LogEntry> = [[1, 2], [3, 4], [5, 6]]
+    .into_iter()
+    .map(|x| {
+      let result =
+        super::super::RollResult::new(x.to_vec(), (x[0] + x[1]) as i16).sides(vec![6, 6]);
+      super::super::LogEntry::new("2d6", "2d6", 0, 1).roll(&result)
+    })
+    .collect();
+  let report = super::LuckReport::new(&entries, &std::collections::HashMap::new());
+
+  assert_eq!(report.dice[0].sides, 6);
+  assert_eq!(report.dice[0].faces, vec![1, 1, 1, 1, 1, 1]);
+  assert!(report.dice[0].fit.statistic.abs() < 1e-9);
+}
+
+#[test]
+fn unsupported() {
+  let exploded = super::super::RollResult::new(vec![6, 2, 3, 1], 12).sides(vec![6; 4]);
+  let contest = super::super::RollResult::new(vec![4, 7], -3).sides(vec![20; 2]);
+  let entries = vec![
+    super::super::LogEntry::new("3d6 ex6", "3d6 ex6", 0, 1).roll(&exploded),
+    super::super::LogEntry::new("1d20 vs 1d20", "1d20 vs 1d20", 0, 1).roll(&contest),
+  ];
+  let report = super::LuckReport::new(&entries, &std::collections::HashMap::new());
+
+  assert_eq!(report.definitions, vec![]);
+  assert_eq!(report.dice, vec![]);
+  assert_eq!(report.unsupported, vec!["3d6 ex6", "1d20 vs 1d20"]);
+}
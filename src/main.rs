@@ -1,5 +1,7 @@
-use clap::Parser;
-use iron_dice::Roller;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+mod cli;
 
 #[derive(Parser)]
 #[command(about = "Throws some dice on the standard output")]
@@ -13,6 +15,10 @@ struct Args {
   ///
   /// - NdM+C where N is the number of dice to throw, default = 1. M is the number of sides per die. C is added to the die results sum. Can be negative as well
   ///
+  /// - dA+dB+NdC throws dice of different sizes together. "d8+d10+2d6"
+  ///
+  /// - XkY+C roll and keep: X exploding d10s adding up, keeping the highest Y. Over ten dice, two rolled become one kept, then every kept one is +2. "7k3+2"
+  ///
   /// - minN takes the N smallest die results
   ///
   /// - maxN takes the N largest die results
@@ -26,31 +32,171 @@ struct Args {
   ///   - sv:N:V used with sc, for every die that shows exactly N the success value is V. Can be spefied multiple times.
   ///
   ///   - fv:N:V used with sc, for every die that shows exactly N the failure value is V. Can be spefied multiple times.
+  ///
+  ///   - rule:CN:V[:W] used with sc, every die compared by C (=, >= or <=) to N is worth V. W is a condition: s on successes, f on failures, mK with K dice showing the same face, tK with K successes or more. Rules, sv and fv apply in order, the first one matching wins.
+  ///
+  /// - rote used with sc, rerolls every failed die once
+  ///
+  /// - wod Storyteller pool: sc8 and ex10 unless given, exceptional success with 5 successes, chance die for pools under one die
+  ///
+  /// - ace makes every die showing its highest face (or exN) roll again, adding up into a single result
+  ///
+  /// - trait dT wild dW Savage Worlds trait roll: acing trait and wild die, the higher is kept. "trait d8+1 wild d6"
+  ///
+  /// - tnN the target to reach, the margin is shown. "2d6+1 tn8". On trait rolls the default is 4 and a raise is counted for every 4 over it instead
+  ///
+  /// - underN the skill to roll at or under, the margin is shown. "1d100 under45"
+  ///
+  ///   - crit:name flags critical results by a built-in table: gurps or brp
+  ///
+  /// - effect Cortex Prime pool: 1s are hitches, the two highest dice are the total, the largest of the rest the effect die. effect:die keeps the largest die for the effect instead
+  ///
+  ///   - up:dN / down:dN steps a die of N sides up or down before throwing. Can be specified multiple times.
+  ///
+  /// - dN:colour names a group of dice, kept apart in the result. "3d6:base+2d6:skill+1d6:gear"
+  ///
+  /// - yz Year Zero pool: sc6 unless given, 1s are banes except on skill dice. Groups are base, skill and gear unless named
+  ///
+  /// - glN reports a glitch when more than half the dice rolled, exploded ones included, show N, a critical glitch with no successes as well. "8d6 sc5 gl1"
+  ///
+  /// - bands:name names the outcome with a band table, from the configuration file or built-in: pbta is 10+ strong hit, 7-9 weak hit, 6- miss
+  ///
+  /// - A vs B opposes two definitions, the higher outcome wins. "1d20+5 vs 1d20+3" "6d6 sc5 vs 4d6 sc5"
+  ///
+  ///   - tie:P breaks ties: tie (default), attacker, defender or reroll
+  ///
+  /// - name or name(A,B) calls a macro defined in the configuration file, passing A and B as parameters
+  ///
+  /// - @name is replaced by the value of an attribute or skill of the character sheet. "1d20+@str_mod" "@dex+@stealth d10 sc8"
   definition: Option<Vec<String>>,
 
-  #[arg(long, short)]
+  #[arg(long, short, global = true)]
   /// the number of simoultaneous throws, default = 1
   throw_number: Option<u8>,
+
+  #[arg(long, short, global = true)]
+  /// the configuration file holding macros, default = $XDG_CONFIG_HOME/iron_dice/config.toml
+  config: Option<PathBuf>,
+
+  #[arg(long, global = true)]
+  /// seeds the random number generator, making the throws reproducible
+  seed: Option<u64>,
+
+  #[arg(long)]
+  /// the character sheet (TOML or JSON) whose attributes and skills can be referenced as @name
+  character: Option<PathBuf>,
+
+  #[arg(long, short)]
+  /// shows how every definition is resolved before throwing
+  explain: bool,
+
+  #[command(subcommand)]
+  command: Option<Command>,
 }
-fn main() {
-  let args = Args::parse();
 
-  let definition = match args.definition {
-    Some(definition) => definition,
-    None => vec![String::from("3d6")],
-  };
+#[derive(Subcommand)]
+enum Command {
+  /// Rolls on a random table
+  Table(cli::TableArgs),
+
+  /// Draws from a deck of cards, jokers included
+  Deck(cli::DeckArgs),
+
+  /// Draws from a bag of tokens
+  Bag(cli::DeckArgs),
+
+  /// Makes an Ironsworn or Starforged move
+  #[command(subcommand)]
+  Ironsworn(cli::IronswornArgs),
+
+  /// Enters a shared table room of "serve --rooms": lines are rolls seen by everyone, "secret DEF" and "reveal [id]" for the GM
+  Join(cli::JoinArgs),
+
+  /// Searches the log of every roll made, showing the seed replaying each one
+  Log(cli::LogArgs),
+
+  /// Compares the logged rolls to their exact distribution: mean, z-score, percentile and streaks per definition, chi-square per die size
+  Luck(cli::LuckArgs),
+
+  /// Asks the yes/no oracle or rolls on the built-in oracle tables
+  #[command(subcommand)]
+  Oracle(cli::OracleArgs),
+
+  /// Makes a Blades in the Dark action roll: 6 is a success, 4-5 a partial, two 6s a critical
+  Blades {
+    /// the number of dice in the pool, 0 included
+    dice: u32,
+  },
+
+  /// Makes an Exalted roll: 7 or more is a success, 10 counts twice
+  Exalted {
+    /// the number of dice in the pool
+    dice: u32,
 
-  let throw_number = match args.throw_number {
-    Some(throw_number) => throw_number,
-    None => 1_u8,
-  };
+    #[arg(long)]
+    /// a damage roll, where 10 counts once
+    damage: bool,
+  },
+
+  /// Makes provably fair rolls: "commit" publishes the hash of a secret server seed, "roll" throws seeded by it, "reveal" shows it
+  Fair(cli::FairArgs),
+
+  /// Makes a GURPS success roll: 3d6 at or under the effective skill, with critical successes and failures
+  Gurps {
+    /// the effective skill
+    skill: i16,
+  },
+
+  /// Tracks initiative: "init goblin:1d20+1 hero:cards" starts a fight, then next, add, remove, delay and show
+  Init(cli::InitArgs),
+
+  /// Throws definitions many times, testing the outcomes against their exact distribution and the faces against fair dice
+  Selftest(cli::SelfTestArgs),
+
+  /// Serves a JSON API over HTTP: POST /roll and /distribution {"definition": "1d20+5"}, GET /tables, POST /tables/name. Shared table rooms over WebSocket with --rooms
+  Serve(cli::ServeArgs),
+
+  /// Makes a Shadowrun test: 5 and 6 are hits, more than half the pool showing 1 is a glitch
+  Shadowrun(cli::ShadowrunArgs),
+
+  /// Throws a provably fair roll again from the revealed server seed, after checking it against the commitment
+  Verify(cli::VerifyArgs),
+
+  /// Throws definitions read line by line. "push" rerolls the last throw's dice showing neither 6 nor 1, "init ..." tracks initiative as the init command, "quit" leaves
+  Repl,
+}
+
+fn main() {
+  let args = Args::parse();
 
-  for definition in definition {
-      let mut r: Roller = definition.parse().unwrap();
+  let throw_number = args.throw_number.unwrap_or(1_u8);
 
-      for _ in 1..=throw_number {
-          let result = r.roll();
-          println!("{:?} => {}", result.dice, result.outcome);
-      }
+  match args.command {
+    Some(Command::Table(table)) => cli::table(table, args.seed, throw_number),
+    Some(Command::Deck(deck)) => cli::deck(deck, false, args.seed, throw_number),
+    Some(Command::Bag(bag)) => cli::deck(bag, true, args.seed, throw_number),
+    Some(Command::Ironsworn(ironsworn)) => cli::ironsworn(ironsworn, args.seed, throw_number),
+    Some(Command::Join(join)) => cli::join(join),
+    Some(Command::Log(log)) => cli::log(log),
+    Some(Command::Luck(luck)) => cli::luck(luck, args.config),
+    Some(Command::Oracle(oracle)) => cli::oracle(oracle, args.seed, throw_number),
+    Some(Command::Blades { dice }) => cli::blades(dice, args.seed, throw_number),
+    Some(Command::Exalted { dice, damage }) => cli::exalted(dice, damage, args.seed, throw_number),
+    Some(Command::Fair(fair)) => cli::fair(fair),
+    Some(Command::Gurps { skill }) => cli::gurps(skill, args.seed, throw_number),
+    Some(Command::Init(init)) => cli::initiative(init, args.config, args.character, args.seed),
+    Some(Command::Selftest(selftest)) => cli::selftest(selftest, args.seed),
+    Some(Command::Serve(serve)) => cli::serve(serve, args.config, args.seed),
+    Some(Command::Shadowrun(shadowrun)) => cli::shadowrun(shadowrun, args.seed, throw_number),
+    Some(Command::Verify(verify)) => cli::verify_roll(verify),
+    Some(Command::Repl) => cli::repl(args.config, args.character, args.seed),
+    None => cli::roll(
+      args.definition,
+      args.config,
+      args.character,
+      args.explain,
+      args.seed,
+      throw_number,
+    ),
   }
 }
@@ -0,0 +1,21 @@
+use super::{Likelihood, Oracle, OracleAnswer, OracleResult};
+
+impl Oracle {
+  /// Asks a yes/no question
+  pub fn ask(&mut self, likelihood: Likelihood) -> OracleResult {
+    let roll = self.roller.roll();
+    let answer = OracleAnswer::new(roll.outcome, likelihood.chance());
+
+    // 100 is a match in Ironsworn, but never a random event in Mythic
+    let double = roll.outcome % 11 == 0 && roll.outcome < 100;
+    let matched = double || roll.outcome == 100;
+    let random_event = double && roll.outcome / 11 <= self.chaos as i16;
+
+    OracleResult {
+      roll,
+      answer,
+      matched,
+      random_event,
+    }
+  }
+}
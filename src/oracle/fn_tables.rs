@@ -0,0 +1,20 @@
+use super::super::{Table, TableErr};
+use super::Oracle;
+
+/// built-in oracle tables: name and file content
+const TABLES: [(&str, &str); 4] = [
+  ("action", include_str!("data/action.toml")),
+  ("theme", include_str!("data/theme.toml")),
+  ("descriptor", include_str!("data/descriptor.toml")),
+  ("focus", include_str!("data/focus.toml")),
+];
+
+impl Oracle {
+  /// The built-in d100 oracle tables: action, theme, descriptor and focus
+  pub fn tables() -> Result<Vec<Table>, TableErr> {
+    TABLES
+      .iter()
+      .map(|(name, content)| Table::from_toml(name, content))
+      .collect()
+  }
+}
@@ -0,0 +1,20 @@
+use super::Likelihood;
+use std::str::FromStr;
+
+/// for idiomatic parsing. "almost-certain", "likely", "50/50", "unlikely", "small-chance"
+impl FromStr for Likelihood {
+  type Err = ();
+
+  fn from_str(descriptor: &str) -> Result<Likelihood, ()> {
+    let descriptor = descriptor.to_lowercase().replace([' ', '_'], "-");
+
+    match descriptor.as_str() {
+      "almost-certain" => Ok(Likelihood::AlmostCertain),
+      "likely" => Ok(Likelihood::Likely),
+      "fifty-fifty" | "50/50" => Ok(Likelihood::FiftyFifty),
+      "unlikely" => Ok(Likelihood::Unlikely),
+      "small-chance" => Ok(Likelihood::SmallChance),
+      _ => Err(()),
+    }
+  }
+}
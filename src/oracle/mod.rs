@@ -0,0 +1,72 @@
+use super::Roller;
+
+// public functions implementation
+mod fn_ask;
+mod fn_tables;
+mod impl_from_str;
+
+// unit tests
+mod ts_ask;
+
+mod oracle_result;
+
+pub use oracle_result::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// How likely a yes is, from almost certain to small chance
+pub enum Likelihood {
+  AlmostCertain,
+  Likely,
+  FiftyFifty,
+  Unlikely,
+  SmallChance,
+}
+
+impl Likelihood {
+  /// The chance of a yes on d100
+  pub fn chance(&self) -> i16 {
+    match self {
+      Likelihood::AlmostCertain => 90,
+      Likelihood::Likely => 75,
+      Likelihood::FiftyFifty => 50,
+      Likelihood::Unlikely => 25,
+      Likelihood::SmallChance => 10,
+    }
+  }
+}
+
+#[derive(Debug)]
+/// A yes/no oracle for solo play, rolling d100 against the chance of a yes
+pub struct Oracle {
+  /// one d100
+  roller: Roller,
+
+  /// Mythic chaos factor, 1 to 9. Matches up to it are random events
+  chaos: u8,
+}
+
+impl Default for Oracle {
+  fn default() -> Self {
+    Self {
+      roller: Roller::new(1, 100),
+      chaos: 5,
+    }
+  }
+}
+
+impl Oracle {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Seeds the random number generator, making the answers reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.roller = self.roller.seed(seed);
+    self
+  }
+
+  pub fn chaos(mut self, chaos: u8) -> Self {
+    self.chaos = chaos;
+    self
+  }
+}
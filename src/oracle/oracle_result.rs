@@ -0,0 +1,66 @@
+use super::super::RollResult;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// Answer of the yes/no oracle
+pub enum OracleAnswer {
+  ExceptionalYes,
+  Yes,
+  No,
+  ExceptionalNo,
+}
+
+impl OracleAnswer {
+  /// A yes when the roll is within the chance. An exceptional answer falls in the outer fifth of
+  /// its side, as in Mythic
+  pub fn new(roll: i16, chance: i16) -> Self {
+    if roll <= chance / 5 {
+      OracleAnswer::ExceptionalYes
+    } else if roll <= chance {
+      OracleAnswer::Yes
+    } else if roll > 100 - (100 - chance) / 5 {
+      OracleAnswer::ExceptionalNo
+    } else {
+      OracleAnswer::No
+    }
+  }
+}
+
+impl fmt::Display for OracleAnswer {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      OracleAnswer::ExceptionalYes => write!(f, "exceptional yes"),
+      OracleAnswer::Yes => write!(f, "yes"),
+      OracleAnswer::No => write!(f, "no"),
+      OracleAnswer::ExceptionalNo => write!(f, "exceptional no"),
+    }
+  }
+}
+
+/// A struct holding the answer of the yes/no oracle
+#[derive(Debug)]
+pub struct OracleResult {
+  /// the d100 roll
+  pub roll: RollResult,
+
+  pub answer: OracleAnswer,
+
+  /// whether the roll is a double, such as 33 or 100. A twist in Ironsworn
+  pub matched: bool,
+
+  /// a match up to the chaos factor, as in Mythic
+  pub random_event: bool,
+}
+
+/// "[33] => yes (match, random event)"
+impl fmt::Display for OracleResult {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:?} => {}", self.roll.dice, self.answer)?;
+
+    match (self.matched, self.random_event) {
+      (true, true) => write!(f, " (match, random event)"),
+      (true, false) => write!(f, " (match)"),
+      _ => Ok(()),
+    }
+  }
+}
@@ -0,0 +1,63 @@
+#[test]
+fn answer() {
+  use super::OracleAnswer;
+  assert_eq!(OracleAnswer::new(10, 50), OracleAnswer::ExceptionalYes);
+  assert_eq!(OracleAnswer::new(11, 50), OracleAnswer::Yes);
+  assert_eq!(OracleAnswer::new(50, 50), OracleAnswer::Yes);
+  assert_eq!(OracleAnswer::new(51, 50), OracleAnswer::No);
+  assert_eq!(OracleAnswer::new(90, 50), OracleAnswer::No);
+  assert_eq!(OracleAnswer::new(91, 50), OracleAnswer::ExceptionalNo);
+  assert_eq!(OracleAnswer::new(18, 90), OracleAnswer::ExceptionalYes);
+  assert_eq!(OracleAnswer::new(99, 90), OracleAnswer::ExceptionalNo);
+}
+
+#[test]
+fn likelihood() {
+  let l: super::Likelihood = "almost certain".parse().unwrap();
+  assert_eq!(l, super::Likelihood::AlmostCertain);
+  let l: super::Likelihood = "50/50".parse().unwrap();
+  assert_eq!(l.chance(), 50);
+  let l: Result<super::Likelihood, ()> = "maybe".parse();
+  assert!(l.is_err());
+}
+
+#[test]
+fn ask() {
+  let mut o = super::Oracle::new();
+  for _ in 1..=1000 {
+    let result = o.ask(super::Likelihood::Likely);
+    assert!(result.roll.outcome > 0 && result.roll.outcome < 101);
+    assert_eq!(
+      result.answer,
+      super::OracleAnswer::new(result.roll.outcome, 75)
+    );
+    let digits = format!("{:02}", result.roll.outcome % 100);
+    assert_eq!(result.matched, digits.as_bytes()[0] == digits.as_bytes()[1]);
+    if !result.matched {
+      assert!(!result.random_event);
+    }
+  }
+}
+
+#[test]
+fn random_event() {
+  let mut o = super::Oracle::new().chaos(9);
+  for _ in 1..=1000 {
+    let result = o.ask(super::Likelihood::FiftyFifty);
+    assert_eq!(
+      result.random_event,
+      result.matched && result.roll.outcome != 100
+    );
+  }
+}
+
+#[test]
+fn tables() {
+  let tables = super::Oracle::tables().unwrap();
+  assert_eq!(tables.len(), 4);
+  for table in tables {
+    assert_eq!(table.roll, "d100");
+    assert_eq!(table.entries.len(), 100);
+    assert!((1..=100).all(|x| table.lookup(x).is_some()));
+  }
+}
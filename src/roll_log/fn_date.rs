@@ -0,0 +1,75 @@
+use super::RollLogErr;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The timestamp at midnight UTC of a "YYYY-MM-DD" date
+pub fn parse_date(date: &str) -> Result<u64, RollLogErr> {
+  let invalid = || RollLogErr::InvalidDate(String::from(date));
+
+  let parts: Vec<&str> = date.trim().split('-').collect();
+  let [year, month, day] = parts.as_slice() else {
+    return Err(invalid());
+  };
+  let year: i64 = year.parse().map_err(|_| invalid())?;
+  let month: u32 = month.parse().map_err(|_| invalid())?;
+  let day: u32 = day.parse().map_err(|_| invalid())?;
+  if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+    return Err(invalid());
+  }
+
+  let days = days_from_civil(year, month, day);
+  if days < 0 || civil_from_days(days) != (year, month, day) {
+    return Err(invalid());
+  }
+
+  Ok(days as u64 * SECONDS_PER_DAY)
+}
+
+/// "YYYY-MM-DD hh:mm:ss", UTC
+pub fn format_timestamp(timestamp: u64) -> String {
+  let (year, month, day) = civil_from_days((timestamp / SECONDS_PER_DAY) as i64);
+  let seconds = timestamp % SECONDS_PER_DAY;
+
+  format!(
+    "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+    year,
+    month,
+    day,
+    seconds / 3600,
+    seconds / 60 % 60,
+    seconds % 60
+  )
+}
+
+/// days since 1970-01-01 of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+  let year = if month <= 2 { year - 1 } else { year };
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let month = month as i64;
+  let day_of_year =
+    (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+  era * 146_097 + day_of_era - 719_468
+}
+
+/// the date of the proleptic Gregorian calendar of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+  let days = days + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days - era * 146_097;
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+  let month = if month_index < 10 {
+    month_index + 3
+  } else {
+    month_index - 9
+  } as u32;
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+  (year, month, day)
+}
@@ -0,0 +1,30 @@
+use super::{format_timestamp, LogEntry};
+
+impl LogEntry {
+  /// A CSV row: time, definition, resolved definition, seed, throw, dice, outcome and result
+  pub fn to_csv(&self) -> String {
+    let dice: Vec<String> = self.dice.iter().map(|x| x.to_string()).collect();
+
+    [
+      format_timestamp(self.timestamp),
+      quote(&self.definition),
+      quote(&self.resolved),
+      self.seed.to_string(),
+      self.throw.to_string(),
+      quote(&dice.join(" ")),
+      self.outcome.to_string(),
+      quote(&self.result),
+    ]
+    .join(",")
+  }
+
+  /// The header of the CSV rows
+  pub fn csv_header() -> &'static str {
+    "time,definition,resolved,seed,throw,dice,outcome,result"
+  }
+}
+
+/// doubles the quotes and wraps the field in quotes
+fn quote(field: &str) -> String {
+  format!("\"{}\"", field.replace('"', "\"\""))
+}
@@ -0,0 +1,29 @@
+use super::{LogEntry, LogFilter, RollLog, RollLogErr};
+
+impl RollLog {
+  /// The logged entries matching the filter, oldest first
+  pub fn search(&self, filter: &LogFilter) -> Result<Vec<LogEntry>, RollLogErr> {
+    Ok(
+      self
+        .entries()?
+        .into_iter()
+        .filter(|x| filter.matches(x))
+        .collect(),
+    )
+  }
+}
+
+impl LogFilter {
+  /// Whether the entry meets every criterion set
+  pub fn matches(&self, entry: &LogEntry) -> bool {
+    let definition = |text: &str| entry.definition.contains(text) || entry.resolved.contains(text);
+
+    self.definition.as_deref().is_none_or(definition)
+      && self
+        .text
+        .as_deref()
+        .is_none_or(|x| definition(x) || entry.result.contains(x))
+      && self.since.is_none_or(|x| entry.timestamp >= x)
+      && self.until.is_none_or(|x| entry.timestamp < x)
+  }
+}
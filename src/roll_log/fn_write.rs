@@ -0,0 +1,55 @@
+use super::{LogEntry, RollLog, RollLogErr};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+impl RollLog {
+  /// Appends an entry at the end of the log, creating it if needed
+  pub fn append(&self, entry: &LogEntry) -> Result<(), RollLogErr> {
+    if let Some(directory) = self.path.parent() {
+      fs::create_dir_all(directory).map_err(|_| RollLogErr::Io)?;
+    }
+
+    let line = serde_json::to_string(entry).map_err(|_| RollLogErr::Parse)?;
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&self.path)
+      .map_err(|_| RollLogErr::Io)?;
+    writeln!(file, "{}", line).map_err(|_| RollLogErr::Io)
+  }
+
+  /// Every logged entry, oldest first. A missing log holds none, and lines that are no entry,
+  /// such as one cut short, are skipped
+  pub fn entries(&self) -> Result<Vec<LogEntry>, RollLogErr> {
+    Ok(self.lines()?.into_iter().filter_map(|x| x.1).collect())
+  }
+
+  /// The number of every line skipped as no entry, from 1
+  pub fn corrupt_lines(&self) -> Result<Vec<usize>, RollLogErr> {
+    Ok(
+      self
+        .lines()?
+        .into_iter()
+        .filter(|x| x.1.is_none())
+        .map(|x| x.0)
+        .collect(),
+    )
+  }
+
+  /// every line but empty ones, numbered from 1, with its entry if it holds one
+  fn lines(&self) -> Result<Vec<(usize, Option<LogEntry>)>, RollLogErr> {
+    if !self.path.exists() {
+      return Ok(Vec::new());
+    }
+
+    Ok(
+      fs::read_to_string(&self.path)
+        .map_err(|_| RollLogErr::Io)?
+        .lines()
+        .enumerate()
+        .filter(|(_, x)| !x.trim().is_empty())
+        .map(|(number, x)| (number + 1, serde_json::from_str(x).ok()))
+        .collect(),
+    )
+  }
+}
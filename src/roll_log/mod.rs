@@ -0,0 +1,169 @@
+use super::{ContestResult, IronswornResult, RollResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// public functions implementation
+mod fn_date;
+mod fn_export;
+mod fn_search;
+mod fn_write;
+
+// unit tests
+mod ts_date;
+mod ts_search;
+
+mod roll_log_err;
+
+pub use fn_date::*;
+pub use roll_log_err::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A logged roll. Throwing the resolved definition with the same seed as many times as the throw
+/// number yields the same dice again
+pub struct LogEntry {
+  /// seconds since the Unix epoch
+  pub timestamp: u64,
+
+  /// the definition as typed, macros and character references included
+  pub definition: String,
+
+  /// the definition actually thrown, "push" appended once pushed, or the command for game system
+  /// rolls. "gurps 12"
+  pub resolved: String,
+
+  /// the seed of the random number generator
+  pub seed: u64,
+
+  /// how many throws the generator made up to this one, this one included
+  pub throw: u32,
+
+  pub dice: Vec<u16>,
+
+  /// the size of the die behind every result, missing from older entries
+  #[serde(default)]
+  pub sides: Vec<u32>,
+
+  pub outcome: i16,
+
+  /// the roll as shown on the standard output
+  pub result: String,
+}
+
+impl LogEntry {
+  /// An entry logged now, the dice and the outcome still to be set
+  pub fn new(definition: &str, resolved: &str, seed: u64, throw: u32) -> Self {
+    Self {
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or(0),
+      definition: String::from(definition),
+      resolved: String::from(resolved),
+      seed,
+      throw,
+      dice: Vec::new(),
+      sides: Vec::new(),
+      outcome: 0,
+      result: String::new(),
+    }
+  }
+
+  /// Sets the dice, their sizes, the outcome and the shown result of a roll
+  pub fn roll(mut self, result: &RollResult) -> Self {
+    self.dice = result.dice.clone();
+    self.sides = result.sides.clone();
+    self.outcome = result.outcome;
+    self.result = result.to_string();
+    self
+  }
+
+  /// Sets the dice of both sides of a contest, the attacker's first. The outcome is the
+  /// attacker's minus the defender's
+  pub fn contest(mut self, result: &ContestResult) -> Self {
+    self.dice = [result.attacker.dice.clone(), result.defender.dice.clone()].concat();
+    self.sides = [result.attacker.sides.clone(), result.defender.sides.clone()].concat();
+    self.outcome = result.attacker.outcome - result.defender.outcome;
+    self.result = result.to_string();
+    self
+  }
+
+  /// Sets the dice of an Ironsworn move, the action die first. The outcome is the score
+  pub fn ironsworn(mut self, result: &IronswornResult) -> Self {
+    let action = result.action.iter();
+    self.dice = action
+      .clone()
+      .flat_map(|x| x.dice.clone())
+      .chain(result.challenge.dice.clone())
+      .collect();
+    self.sides = action
+      .flat_map(|x| x.sides.clone())
+      .chain(result.challenge.sides.clone())
+      .collect();
+    self.outcome = result.score;
+    self.result = result.to_string();
+    self
+  }
+
+  /// Sets the time of the roll, in seconds since the Unix epoch
+  pub fn timestamp(mut self, timestamp: u64) -> Self {
+    self.timestamp = timestamp;
+    self
+  }
+}
+
+#[derive(Debug)]
+/// The log of every roll, one JSON object per line
+pub struct RollLog {
+  path: PathBuf,
+}
+
+impl RollLog {
+  pub fn new(path: &Path) -> Self {
+    Self {
+      path: path.to_path_buf(),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+/// Picks the logged rolls to show. Every criterion set must match
+pub struct LogFilter {
+  /// text found in the definition, typed or resolved
+  definition: Option<String>,
+
+  /// text found in the definition or the shown result
+  text: Option<String>,
+
+  /// earliest timestamp, included
+  since: Option<u64>,
+
+  /// latest timestamp, excluded
+  until: Option<u64>,
+}
+
+impl LogFilter {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn definition(mut self, definition: &str) -> Self {
+    self.definition = Some(String::from(definition));
+    self
+  }
+
+  pub fn text(mut self, text: &str) -> Self {
+    self.text = Some(String::from(text));
+    self
+  }
+
+  pub fn since(mut self, timestamp: u64) -> Self {
+    self.since = Some(timestamp);
+    self
+  }
+
+  pub fn until(mut self, timestamp: u64) -> Self {
+    self.until = Some(timestamp);
+    self
+  }
+}
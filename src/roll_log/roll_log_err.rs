@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq)]
+/// Error returned while writing or reading the roll log
+pub enum RollLogErr {
+  Io,
+  Parse,
+  InvalidDate(String),
+}
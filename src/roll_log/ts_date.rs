@@ -0,0 +1,22 @@
+#[test]
+fn parse_date() {
+  assert_eq!(super::parse_date("1970-01-01").unwrap(), 0);
+  assert_eq!(super::parse_date("2024-02-29").unwrap(), 1_709_164_800);
+  assert_eq!(
+    super::parse_date("2023-02-29").unwrap_err(),
+    super::RollLogErr::InvalidDate(String::from("2023-02-29"))
+  );
+  assert_eq!(
+    super::parse_date("yesterday").unwrap_err(),
+    super::RollLogErr::InvalidDate(String::from("yesterday"))
+  );
+}
+
+#[test]
+fn format_timestamp() {
+  assert_eq!(super::format_timestamp(0), "1970-01-01 00:00:00");
+  assert_eq!(
+    super::format_timestamp(1_709_164_800 + 3_723),
+    "2024-02-29 01:02:03"
+  );
+}
@@ -0,0 +1,69 @@
+#[test]
+fn append_and_search() {
+  let path = std::env::temp_dir().join(format!("iron_dice_log_{}.jsonl", std::process::id()));
+  let _ = std::fs::remove_file(&path);
+  let log = super::RollLog::new(&path);
+
+  for (definition, timestamp) in [("1d20+5", 100), ("3d6", 200), ("1d20", 300)] {
+    let r: super::super::Roller = definition.parse().unwrap();
+    let entry = super::LogEntry::new(definition, definition, 7, 1)
+      .roll(&r.seed(7).roll())
+      .timestamp(timestamp);
+    log.append(&entry).unwrap();
+  }
+  assert_eq!(log.entries().unwrap().len(), 3);
+
+  let found = log
+    .search(&super::LogFilter::new().definition("1d20"))
+    .unwrap();
+  assert_eq!(found.len(), 2);
+
+  let found = log
+    .search(&super::LogFilter::new().since(200).until(300))
+    .unwrap();
+  assert_eq!(found[0].definition, "3d6");
+  assert_eq!(found.len(), 1);
+
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn replayable() {
+  let r: super::super::Roller = String::from("4d6 max3").parse().unwrap();
+  let e = super::LogEntry::new("4d6 max3", "4d6 max3", 7, 1).roll(&r.seed(7).roll());
+
+  let r: super::super::Roller = e.resolved.parse().unwrap();
+  assert_eq!(r.seed(e.seed).roll().dice, e.dice);
+}
+
+#[test]
+fn csv() {
+  let mut e = super::LogEntry::new("1d1", "1d1", 7, 1)
+    .roll(&super::super::RollResult::new(vec![1], 1))
+    .timestamp(0);
+  e.result = String::from("say \"hi\"");
+  assert_eq!(
+    e.to_csv(),
+    "1970-01-01 00:00:00,\"1d1\",\"1d1\",7,1,\"1\",1,\"say \"\"hi\"\"\""
+  );
+}
+
+#[test]
+fn corrupt_lines() {
+  let path = std::env::temp_dir().join(format!("iron_dice_corrupt_{}.jsonl", std::process::id()));
+  let _ = std::fs::remove_file(&path);
+  let log = super::RollLog::new(&path);
+
+  let r: super::super::Roller = "1d20".parse().unwrap();
+  let entry = super::LogEntry::new("1d20", "1d20", 7, 1).roll(&r.seed(7).roll());
+  log.append(&entry).unwrap();
+  let mut content = std::fs::read_to_string(&path).unwrap();
+  content.push_str("{\"timestamp\": 12, \"defin\n\n");
+  std::fs::write(&path, content).unwrap();
+  log.append(&entry).unwrap();
+
+  assert_eq!(log.entries().unwrap().len(), 2);
+  assert_eq!(log.corrupt_lines().unwrap(), vec![2]);
+
+  std::fs::remove_file(&path).unwrap();
+}
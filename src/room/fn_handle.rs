@@ -0,0 +1,257 @@
+use super::super::{Contest, LogEntry, Roller};
+use super::{ClientMessage, Outgoing, PendingRoll, RollEvent, Room, Rooms, ServerMessage};
+use rand::Rng;
+
+impl Rooms {
+  /// Handles a message of the client, returning what to send to whom
+  pub fn handle(&mut self, client: u64, message: ClientMessage) -> Vec<Outgoing> {
+    let outgoing = match message {
+      ClientMessage::Join {
+        room,
+        name,
+        gm,
+        token,
+      } => self.join(client, &room, &name, gm, token.as_deref()),
+      ClientMessage::Roll { definition, secret } => self.roll(client, &definition, secret),
+      ClientMessage::Reveal { id } => self.reveal(client, id),
+      ClientMessage::Leave => Ok(self.disconnect(client)),
+    };
+
+    outgoing.unwrap_or_else(|message| {
+      vec![Outgoing {
+        to: vec![client],
+        message: ServerMessage::Error { message },
+      }]
+    })
+  }
+
+  /// Takes the client out of its room, telling the other members
+  pub fn disconnect(&mut self, client: u64) -> Vec<Outgoing> {
+    let Some(name) = self.clients.remove(&client) else {
+      return Vec::new();
+    };
+    let Some(room) = self.rooms.get_mut(&name) else {
+      return Vec::new();
+    };
+
+    let left = room
+      .members
+      .iter()
+      .position(|(x, _)| *x == client)
+      .map(|x| room.members.remove(x).1)
+      .unwrap_or_default();
+    if room.gm == Some(client) {
+      room.gm = None;
+    }
+
+    let to = members(room);
+    if room.members.is_empty() {
+      self.rooms.remove(&name);
+    }
+
+    vec![Outgoing {
+      to,
+      message: ServerMessage::Left { name: left },
+    }]
+  }
+
+  fn join(
+    &mut self,
+    client: u64,
+    name: &str,
+    member: &str,
+    gm: bool,
+    token: Option<&str>,
+  ) -> Result<Vec<Outgoing>, String> {
+    if self.clients.contains_key(&client) {
+      return Err(String::from("already in a room"));
+    }
+    if name.trim().is_empty() || member.trim().is_empty() {
+      return Err(String::from("a room and a name are needed"));
+    }
+
+    let room = self.rooms.entry(String::from(name)).or_default();
+    if room.members.iter().any(|(_, x)| x == member) {
+      return Err(format!("{} is already in the room", member));
+    }
+    if gm && room.gm.is_some() {
+      return Err(String::from("the room already has a GM"));
+    }
+    // secret rolls stay in the room, only the GM who made them may take the seat back
+    if gm && room.gm_token.is_some() && room.gm_token.as_deref() != token {
+      return Err(String::from("the GM token is needed to take the seat back"));
+    }
+
+    room.members.push((client, String::from(member)));
+    if gm {
+      room.gm = Some(client);
+    }
+    self.clients.insert(client, String::from(name));
+
+    let mut outgoing = vec![Outgoing {
+      to: members(room),
+      message: ServerMessage::Joined {
+        room: String::from(name),
+        name: String::from(member),
+        gm,
+        members: room.members.iter().map(|(_, x)| x.clone()).collect(),
+      },
+    }];
+    if gm && room.gm_token.is_none() {
+      let token: [u8; 16] = rand::random();
+      let token = hex::encode(token);
+      room.gm_token = Some(token.clone());
+      outgoing.push(Outgoing {
+        to: vec![client],
+        message: ServerMessage::GmToken { token },
+      });
+    }
+
+    Ok(outgoing)
+  }
+
+  fn roll(&mut self, client: u64, definition: &str, secret: bool) -> Result<Vec<Outgoing>, String> {
+    let mut pending = self.prepare(client, definition, secret)?;
+    pending.throw();
+    self.publish(client, pending)
+  }
+
+  /// Checks a roll of the client and seeds it, to be thrown and then published
+  pub fn prepare(
+    &mut self,
+    client: u64,
+    definition: &str,
+    secret: bool,
+  ) -> Result<PendingRoll, String> {
+    let name = self.clients.get(&client).ok_or("join a room first")?;
+    let room = self.rooms.get(name).ok_or("join a room first")?;
+    if secret && room.gm != Some(client) {
+      return Err(String::from("only the GM rolls in secret"));
+    }
+
+    let resolved = self
+      .config
+      .expand(definition)
+      .map_err(|x| format!("{:?}", x))?;
+    if Contest::is_contest(&resolved) {
+      return Err(String::from("contests are not supported in rooms"));
+    }
+    let r = Roller::parse_with_bands(&resolved, &self.bands).map_err(|x| format!("{:?}", x))?;
+
+    let seed = self.rng.gen();
+    Ok(PendingRoll {
+      definition: String::from(definition),
+      resolved,
+      roller: r.seed(seed),
+      seed,
+      secret,
+      result: None,
+    })
+  }
+
+  /// Shows a thrown roll to the room of the client, or to the GM alone when secret
+  pub fn publish(&mut self, client: u64, pending: PendingRoll) -> Result<Vec<Outgoing>, String> {
+    // the client may have left while the roll was thrown
+    let name = self.clients.get(&client).ok_or("join a room first")?;
+    let room = self.rooms.get_mut(name).ok_or("join a room first")?;
+
+    let PendingRoll {
+      definition,
+      resolved,
+      mut roller,
+      seed,
+      secret,
+      result,
+    } = pending;
+    let result = result.unwrap_or_else(|| roller.roll());
+    if let Some(log) = &self.log {
+      // a log that cannot be written fails no roll
+      let _ = log.append(&LogEntry::new(&definition, &resolved, seed, 1).roll(&result));
+    }
+    let event = RollEvent {
+      id: self.next_roll,
+      name: member(room, client),
+      definition,
+      seed,
+      text: result.to_string(),
+      result,
+      secret,
+      revealed: false,
+    };
+    self.next_roll += 1;
+
+    if !secret {
+      return Ok(vec![Outgoing {
+        to: members(room),
+        message: ServerMessage::Roll(event),
+      }]);
+    }
+
+    // the GM sees the roll, the others only that it was made
+    let outgoing = vec![
+      Outgoing {
+        to: vec![client],
+        message: ServerMessage::Roll(event.clone()),
+      },
+      Outgoing {
+        to: members(room).into_iter().filter(|x| *x != client).collect(),
+        message: ServerMessage::SecretRoll {
+          id: event.id,
+          name: event.name.clone(),
+        },
+      },
+    ];
+    room.secrets.push(event);
+
+    Ok(outgoing)
+  }
+
+  fn reveal(&mut self, client: u64, id: Option<u64>) -> Result<Vec<Outgoing>, String> {
+    let name = self.clients.get(&client).ok_or("join a room first")?;
+    let room = self.rooms.get_mut(name).ok_or("join a room first")?;
+    if room.gm != Some(client) {
+      return Err(String::from("only the GM reveals secret rolls"));
+    }
+
+    let revealed: Vec<RollEvent> = match id {
+      None => room.secrets.drain(..).collect(),
+      Some(id) => {
+        let position = room
+          .secrets
+          .iter()
+          .position(|x| x.id == id)
+          .ok_or(format!("no secret roll {}", id))?;
+        vec![room.secrets.remove(position)]
+      }
+    };
+
+    let to = members(room);
+    Ok(
+      revealed
+        .into_iter()
+        .map(|mut event| {
+          event.revealed = true;
+          Outgoing {
+            to: to.clone(),
+            message: ServerMessage::Roll(event),
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+/// every client in the room
+fn members(room: &Room) -> Vec<u64> {
+  room.members.iter().map(|(x, _)| *x).collect()
+}
+
+/// the name of the client in the room
+fn member(room: &Room, client: u64) -> String {
+  room
+    .members
+    .iter()
+    .find(|(x, _)| *x == client)
+    .map(|(_, x)| x.clone())
+    .unwrap_or_default()
+}
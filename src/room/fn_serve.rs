@@ -0,0 +1,203 @@
+use super::super::RateLimiter;
+use super::{ClientMessage, Outgoing, Rooms, ServerMessage};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+use tungstenite::handshake::server::{Callback, ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::{Error, Message, WebSocket};
+
+/// how long a connection waits for a message before sending what is pending
+const POLL: Duration = Duration::from_millis(20);
+
+/// the queue of messages waiting to be sent to every client
+type Senders = Arc<Mutex<HashMap<u64, Sender<String>>>>;
+
+/// the message allowance of every client address, shared by its connections
+type Limiter = Arc<Mutex<Option<RateLimiter>>>;
+
+#[derive(Debug, Clone, Default)]
+/// Who may connect to the rooms and how often they may send messages
+pub struct RoomPolicy {
+  /// the origin allowed to connect from a browser, "*" or none for any
+  pub origin: Option<String>,
+
+  /// messages per second and at once allowed to every client, none for no limit
+  pub rate: Option<(f64, f64)>,
+}
+
+/// Serves the rooms over WebSocket, one thread per client, until the listener fails
+pub fn serve_rooms(listener: TcpListener, rooms: Rooms, policy: RoomPolicy) {
+  let rooms = Arc::new(Mutex::new(rooms));
+  let senders: Senders = Arc::new(Mutex::new(HashMap::new()));
+  let limiter: Limiter = Arc::new(Mutex::new(
+    policy
+      .rate
+      .map(|(per_second, burst)| RateLimiter::new(per_second, burst)),
+  ));
+
+  for (client, stream) in (1_u64..).zip(listener.incoming()) {
+    let Ok(stream) = stream else {
+      continue;
+    };
+
+    let rooms = Arc::clone(&rooms);
+    let senders = Arc::clone(&senders);
+    let limiter = Arc::clone(&limiter);
+    let origin = policy.origin.clone();
+    thread::spawn(move || connect(client, stream, rooms, senders, limiter, origin));
+  }
+}
+
+/// refuses the handshake of browsers on another origin than the allowed one
+struct OriginCheck {
+  origin: Option<String>,
+}
+
+impl Callback for OriginCheck {
+  fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    let allowed = match (&self.origin, request.headers().get("Origin")) {
+      (None, _) | (_, None) => true,
+      (Some(origin), Some(header)) => header.as_bytes() == origin.as_bytes(),
+    };
+    match allowed {
+      true => Ok(response),
+      false => {
+        let mut refusal = ErrorResponse::new(Some(String::from("origin not allowed")));
+        *refusal.status_mut() = StatusCode::FORBIDDEN;
+        Err(refusal)
+      }
+    }
+  }
+}
+
+/// handles a client from the handshake to the disconnection
+fn connect(
+  client: u64,
+  stream: TcpStream,
+  rooms: Arc<Mutex<Rooms>>,
+  senders: Senders,
+  limiter: Limiter,
+  origin: Option<String>,
+) {
+  // reconnecting gets a client no new allowance
+  let address = stream
+    .peer_addr()
+    .map(|x| x.ip().to_string())
+    .unwrap_or_default();
+
+  let check = OriginCheck {
+    origin: origin.filter(|x| x != "*"),
+  };
+  let Ok(mut socket) = tungstenite::accept_hdr(stream, check) else {
+    return;
+  };
+  if socket.get_ref().set_read_timeout(Some(POLL)).is_err() {
+    return;
+  }
+
+  let (sender, receiver) = mpsc::channel();
+  lock(&senders).insert(client, sender);
+
+  listen(
+    client,
+    &address,
+    &mut socket,
+    &receiver,
+    &rooms,
+    &senders,
+    &limiter,
+  );
+
+  let outgoing = lock(&rooms).disconnect(client);
+  dispatch(outgoing, &senders);
+  lock(&senders).remove(&client);
+}
+
+/// reads the messages of the client and sends it those of the others, until it leaves. Clients
+/// are rate limited by address
+fn listen(
+  client: u64,
+  address: &str,
+  socket: &mut WebSocket<TcpStream>,
+  receiver: &Receiver<String>,
+  rooms: &Mutex<Rooms>,
+  senders: &Senders,
+  limiter: &Mutex<Option<RateLimiter>>,
+) {
+  loop {
+    match socket.read() {
+      Ok(Message::Text(text)) => {
+        let allowed = match &mut *lock(limiter) {
+          Some(limiter) => limiter.allow(address, Instant::now()),
+          None => true,
+        };
+        let outgoing = match (allowed, serde_json::from_str::<ClientMessage>(&text)) {
+          (false, _) => error(client, "too many messages"),
+          (true, Err(err)) => error(client, &format!("invalid message: {}", err)),
+          // thrown without holding the rooms, so that no roll holds up the others
+          (true, Ok(ClientMessage::Roll { definition, secret })) => {
+            let pending = lock(rooms).prepare(client, &definition, secret);
+            match pending {
+              Ok(mut pending) => {
+                pending.throw();
+                let published = lock(rooms).publish(client, pending);
+                published.unwrap_or_else(|message| error(client, &message))
+              }
+              Err(message) => error(client, &message),
+            }
+          }
+          (true, Ok(message)) => lock(rooms).handle(client, message),
+        };
+        dispatch(outgoing, senders);
+      }
+      Ok(Message::Close(_)) => return,
+      Ok(_) => (),
+      // nothing to read yet
+      Err(Error::Io(err)) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+      Err(_) => return,
+    }
+
+    for text in receiver.try_iter() {
+      if socket.send(Message::Text(text)).is_err() {
+        return;
+      }
+    }
+  }
+}
+
+/// queues every message for its recipients
+fn dispatch(outgoing: Vec<Outgoing>, senders: &Senders) {
+  let senders = lock(senders);
+
+  for Outgoing { to, message } in outgoing {
+    let Ok(text) = serde_json::to_string(&message) else {
+      continue;
+    };
+    for client in to {
+      if let Some(sender) = senders.get(&client) {
+        // a client gone meanwhile misses the message
+        let _ = sender.send(text.clone());
+      }
+    }
+  }
+}
+
+/// an error for the client alone
+fn error(client: u64, message: &str) -> Vec<Outgoing> {
+  vec![Outgoing {
+    to: vec![client],
+    message: ServerMessage::Error {
+      message: String::from(message),
+    },
+  }]
+}
+
+/// the content of the mutex, even when a thread panicked holding it: the other clients go on
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+  mutex.lock().unwrap_or_else(|x| x.into_inner())
+}
@@ -0,0 +1,215 @@
+use super::{Band, Config, RollLog, RollResult, Roller};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// public functions implementation
+mod fn_handle;
+mod fn_serve;
+
+// unit tests
+mod ts_handle;
+mod ts_serve;
+
+pub use fn_serve::*;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+/// A message from a client, as JSON: {"type": "roll", "definition": "1d20+5"}
+pub enum ClientMessage {
+  /// enters a room, creating it if needed. A room has at most one GM, and the seat left by the
+  /// GM is taken back with the token they were given
+  Join {
+    room: String,
+    name: String,
+    #[serde(default)]
+    gm: bool,
+    token: Option<String>,
+  },
+
+  /// throws a definition for the whole room, or for the GM only when secret
+  Roll {
+    definition: String,
+    #[serde(default)]
+    secret: bool,
+  },
+
+  /// shows a secret roll of the GM to the room, every pending one without an id
+  Reveal {
+    id: Option<u64>,
+  },
+
+  Leave,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+/// A message to clients, as JSON: {"type": "roll", "name": "Ann", ...}
+pub enum ServerMessage {
+  /// someone entered the room
+  Joined {
+    room: String,
+    name: String,
+    gm: bool,
+    members: Vec<String>,
+  },
+
+  /// the token taking the GM seat back, sent to the first GM of the room only
+  GmToken {
+    token: String,
+  },
+
+  /// someone left the room
+  Left {
+    name: String,
+  },
+
+  /// a roll, public, secret to the GM or revealed
+  Roll(RollEvent),
+
+  /// the GM rolled in secret, shown to the other members without the roll
+  SecretRoll {
+    id: u64,
+    name: String,
+  },
+
+  Error {
+    message: String,
+  },
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// A roll made in a room
+pub struct RollEvent {
+  /// numbers rolls across rooms, used to reveal secret ones
+  pub id: u64,
+
+  /// who rolled
+  pub name: String,
+
+  pub definition: String,
+
+  /// the seed of the random number generator, throwing the definition with it yields the roll
+  pub seed: u64,
+
+  pub result: RollResult,
+
+  /// the roll as shown on the standard output
+  pub text: String,
+
+  pub secret: bool,
+
+  /// whether a secret roll was revealed to the room
+  pub revealed: bool,
+}
+
+#[derive(Debug)]
+/// A roll checked and seeded by the rooms, to be thrown without holding them up and then
+/// published to the room
+pub struct PendingRoll {
+  definition: String,
+
+  /// the definition with macros expanded
+  resolved: String,
+
+  roller: Roller,
+  seed: u64,
+  secret: bool,
+
+  /// the roll, once thrown
+  result: Option<RollResult>,
+}
+
+impl PendingRoll {
+  pub fn throw(&mut self) {
+    self.result = Some(self.roller.roll());
+  }
+}
+
+#[derive(Debug)]
+/// A message to some of the clients
+pub struct Outgoing {
+  /// the clients to send it to
+  pub to: Vec<u64>,
+
+  pub message: ServerMessage,
+}
+
+#[derive(Debug, Default)]
+/// a room and the clients in it
+struct Room {
+  /// every member as (client, name), in order of arrival
+  members: Vec<(u64, String)>,
+
+  /// the client acting as GM, if any
+  gm: Option<u64>,
+
+  /// given to the first GM, needed to take the seat back once they left
+  gm_token: Option<String>,
+
+  /// secret rolls not revealed yet
+  secrets: Vec<RollEvent>,
+}
+
+#[derive(Debug)]
+/// Shared tables where clients join rooms and roll for everyone to see. Clients are numbered by
+/// the caller, messages are handled one at a time
+pub struct Rooms {
+  rooms: HashMap<String, Room>,
+
+  /// the room of every client
+  clients: HashMap<u64, String>,
+
+  /// the id of the next roll
+  next_roll: u64,
+
+  /// macros and band tables
+  config: Config,
+  bands: HashMap<String, Vec<Band>>,
+
+  /// the roll log every roll is appended to, if any
+  log: Option<RollLog>,
+
+  /// random number generator, seeding every roller
+  rng: StdRng,
+}
+
+impl Default for Rooms {
+  fn default() -> Self {
+    Self {
+      rooms: HashMap::new(),
+      clients: HashMap::new(),
+      next_roll: 1,
+      config: Config::default(),
+      bands: HashMap::new(),
+      log: None,
+      rng: StdRng::from_entropy(),
+    }
+  }
+}
+
+impl Rooms {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the configuration whose macros and band tables definitions may use
+  pub fn config(mut self, config: Config, bands: HashMap<String, Vec<Band>>) -> Self {
+    self.config = config;
+    self.bands = bands;
+    self
+  }
+
+  /// Appends every roll to the log, along with its seed
+  pub fn log(mut self, log: Option<RollLog>) -> Self {
+    self.log = log;
+    self
+  }
+
+  /// Seeds the random number generator, making rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self
+  }
+}
@@ -0,0 +1,242 @@
+#[test]
+fn join_and_roll() {
+  let mut rooms = super::Rooms::new().seed(1);
+  let joined = rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Gina", "gm": true}"#)
+      .unwrap(),
+  );
+  assert_eq!(joined[1].to, vec![1]);
+  match &joined[1].message {
+    super::ServerMessage::GmToken { token } => assert_eq!(token.len(), 32),
+    x => panic!("{:?}", x),
+  }
+
+  let joined = rooms.handle(
+    2,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Ann"}"#).unwrap(),
+  );
+  assert_eq!(joined[0].to, vec![1, 2]);
+  match &joined[0].message {
+    super::ServerMessage::Joined { members, .. } => assert_eq!(members, &vec!["Gina", "Ann"]),
+    x => panic!("{:?}", x),
+  }
+
+  let rolled = rooms.handle(
+    2,
+    serde_json::from_str(r#"{"type": "roll", "definition": "1d20+5"}"#).unwrap(),
+  );
+  assert_eq!(rolled[0].to, vec![1, 2]);
+  match &rolled[0].message {
+    super::ServerMessage::Roll(event) => {
+      assert_eq!(event.name, "Ann");
+      assert!(!event.secret);
+      let r: super::super::Roller = String::from("1d20+5").parse().unwrap();
+      assert_eq!(r.seed(event.seed).roll().outcome, event.result.outcome);
+    }
+    x => panic!("{:?}", x),
+  }
+}
+
+#[test]
+fn secret_and_reveal() {
+  let mut rooms = super::Rooms::new();
+  rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Gina", "gm": true}"#)
+      .unwrap(),
+  );
+  rooms.handle(
+    2,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Ann"}"#).unwrap(),
+  );
+
+  let rolled = rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "roll", "definition": "1d100", "secret": true}"#).unwrap(),
+  );
+  assert_eq!(rolled[0].to, vec![1]);
+  assert_eq!(rolled[1].to, vec![2]);
+  let id = match (&rolled[0].message, &rolled[1].message) {
+    (super::ServerMessage::Roll(event), super::ServerMessage::SecretRoll { id, .. }) => {
+      assert_eq!(event.id, *id);
+      *id
+    }
+    x => panic!("{:?}", x),
+  };
+
+  let revealed = rooms.handle(2, super::ClientMessage::Reveal { id: None });
+  assert!(matches!(
+    revealed[0].message,
+    super::ServerMessage::Error { .. }
+  ));
+
+  let revealed = rooms.handle(1, super::ClientMessage::Reveal { id: Some(id) });
+  assert_eq!(revealed[0].to, vec![1, 2]);
+  match &revealed[0].message {
+    super::ServerMessage::Roll(event) => assert!(event.secret && event.revealed),
+    x => panic!("{:?}", x),
+  }
+
+  let revealed = rooms.handle(1, super::ClientMessage::Reveal { id: Some(id) });
+  assert!(matches!(
+    revealed[0].message,
+    super::ServerMessage::Error { .. }
+  ));
+}
+
+#[test]
+fn errors() {
+  let mut rooms = super::Rooms::new();
+  let rolled = rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "roll", "definition": "1d6"}"#).unwrap(),
+  );
+  assert_eq!(rolled[0].to, vec![1]);
+  assert!(matches!(
+    rolled[0].message,
+    super::ServerMessage::Error { .. }
+  ));
+
+  rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Gina", "gm": true}"#)
+      .unwrap(),
+  );
+  for outgoing in [
+    rooms.handle(
+      2,
+      serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Gina"}"#).unwrap(),
+    ),
+    rooms.handle(
+      3,
+      serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Bob", "gm": true}"#)
+        .unwrap(),
+    ),
+    rooms.handle(
+      1,
+      serde_json::from_str(r#"{"type": "roll", "definition": "fast"}"#).unwrap(),
+    ),
+    rooms.handle(
+      1,
+      serde_json::from_str(r#"{"type": "roll", "definition": "1d20 vs 1d20"}"#).unwrap(),
+    ),
+  ] {
+    assert!(matches!(
+      outgoing[0].message,
+      super::ServerMessage::Error { .. }
+    ));
+  }
+
+  rooms.handle(
+    2,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Ann"}"#).unwrap(),
+  );
+  let rolled = rooms.handle(
+    2,
+    serde_json::from_str(r#"{"type": "roll", "definition": "1d6", "secret": true}"#).unwrap(),
+  );
+  assert!(matches!(
+    rolled[0].message,
+    super::ServerMessage::Error { .. }
+  ));
+}
+
+#[test]
+fn pending_roll() {
+  let mut rooms = super::Rooms::new();
+  rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Ann"}"#).unwrap(),
+  );
+  assert!(rooms.prepare(1, "1d100", true).is_err());
+  assert_eq!(
+    rooms.prepare(1, "1d6 ex1", false).unwrap_err(),
+    "EndlessExplosion"
+  );
+
+  let mut pending = rooms.prepare(1, "1d20", false).unwrap();
+  pending.throw();
+  let published = rooms.publish(1, pending).unwrap();
+  assert!(matches!(
+    published[0].message,
+    super::ServerMessage::Roll(_)
+  ));
+
+  // the client left while the roll was thrown
+  let pending = rooms.prepare(1, "1d20", false).unwrap();
+  rooms.disconnect(1);
+  assert!(rooms.publish(1, pending).is_err());
+}
+
+#[test]
+fn leave() {
+  let mut rooms = super::Rooms::new();
+  let gina = rooms.handle(
+    1,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Gina", "gm": true}"#)
+      .unwrap(),
+  );
+  rooms.handle(
+    2,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Ann"}"#).unwrap(),
+  );
+
+  let left = rooms.disconnect(1);
+  assert_eq!(left[0].to, vec![2]);
+
+  // the GM seat is free again, for the GM only
+  let joined = rooms.handle(
+    3,
+    serde_json::from_str(r#"{"type": "join", "room": "table", "name": "Bob", "gm": true}"#)
+      .unwrap(),
+  );
+  assert!(matches!(
+    joined[0].message,
+    super::ServerMessage::Error { .. }
+  ));
+
+  let token = match &gina[1].message {
+    super::ServerMessage::GmToken { token } => token.clone(),
+    x => panic!("{:?}", x),
+  };
+  let joined = rooms.handle(
+    3,
+    super::ClientMessage::Join {
+      room: String::from("table"),
+      name: String::from("Gina"),
+      gm: true,
+      token: Some(token),
+    },
+  );
+  assert_eq!(joined.len(), 1);
+  assert!(matches!(
+    joined[0].message,
+    super::ServerMessage::Joined { gm: true, .. }
+  ));
+
+  rooms.handle(2, super::ClientMessage::Leave);
+  rooms.handle(3, super::ClientMessage::Leave);
+  assert!(rooms.rooms.is_empty());
+}
+
+#[test]
+fn json() {
+  let message: super::ClientMessage =
+    serde_json::from_str(r#"{"type": "roll", "definition": "1d20"}"#).unwrap();
+  assert_eq!(
+    message,
+    super::ClientMessage::Roll {
+      definition: String::from("1d20"),
+      secret: false
+    }
+  );
+
+  let left = super::ServerMessage::Left {
+    name: String::from("Ann"),
+  };
+  assert_eq!(
+    serde_json::to_string(&left).unwrap(),
+    r#"{"type":"left","name":"Ann"}"#
+  );
+}
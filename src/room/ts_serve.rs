@@ -0,0 +1,107 @@
+#[test]
+fn broadcast() {
+  let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+  let port = listener.local_addr().unwrap().port();
+  std::thread::spawn(move || {
+    super::serve_rooms(listener, super::Rooms::new(), super::RoomPolicy::default())
+  });
+
+  type Client = tungstenite::WebSocket<tungstenite::stream::MaybeTlsStream<std::net::TcpStream>>;
+  let connect = || -> Client {
+    let (socket, _) = tungstenite::connect(format!("ws://127.0.0.1:{}", port)).unwrap();
+    if let tungstenite::stream::MaybeTlsStream::Plain(stream) = socket.get_ref() {
+      let timeout = std::time::Duration::from_secs(5);
+      stream.set_read_timeout(Some(timeout)).unwrap();
+    }
+    socket
+  };
+  let send = |client: &mut Client, message: &str| {
+    let message = tungstenite::Message::Text(String::from(message));
+    client.send(message).unwrap();
+  };
+  // the server sends text messages only
+  let receive = |client: &mut Client| -> serde_json::Value {
+    serde_json::from_str(client.read().unwrap().to_text().unwrap()).unwrap()
+  };
+
+  let mut gm = connect();
+  send(
+    &mut gm,
+    r#"{"type": "join", "room": "table", "name": "Gina", "gm": true}"#,
+  );
+  assert_eq!(receive(&mut gm)["type"], "joined");
+  assert_eq!(receive(&mut gm)["type"], "gm_token");
+
+  let mut player = connect();
+  send(
+    &mut player,
+    r#"{"type": "join", "room": "table", "name": "Ann"}"#,
+  );
+  assert_eq!(
+    receive(&mut player)["members"],
+    serde_json::json!(["Gina", "Ann"])
+  );
+  assert_eq!(receive(&mut gm)["name"], "Ann");
+
+  send(&mut player, r#"{"type": "roll", "definition": "3d6"}"#);
+  for client in [&mut gm, &mut player] {
+    let message = receive(client);
+    assert_eq!(message["type"], "roll");
+    assert_eq!(message["name"], "Ann");
+    assert_eq!(message["result"]["dice"].as_array().unwrap().len(), 3);
+  }
+
+  send(
+    &mut gm,
+    r#"{"type": "roll", "definition": "1d100", "secret": true}"#,
+  );
+  let secret = receive(&mut gm);
+  assert_eq!(secret["secret"], true);
+  let notice = receive(&mut player);
+  assert_eq!(notice["type"], "secret_roll");
+  assert!(notice.get("result").is_none());
+
+  send(&mut gm, r#"{"type": "reveal"}"#);
+  let revealed = receive(&mut player);
+  assert_eq!(revealed["revealed"], true);
+  assert_eq!(revealed["result"], secret["result"]);
+
+  gm.close(None).unwrap();
+  assert_eq!(receive(&mut player)["type"], "left");
+}
+
+#[test]
+fn rate_limit_by_address() {
+  let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+  let port = listener.local_addr().unwrap().port();
+  let policy = super::RoomPolicy {
+    origin: None,
+    rate: Some((0.001, 1.0)),
+  };
+  std::thread::spawn(move || super::serve_rooms(listener, super::Rooms::new(), policy));
+
+  type Client = tungstenite::WebSocket<tungstenite::stream::MaybeTlsStream<std::net::TcpStream>>;
+  let connect = || -> Client {
+    let (socket, _) = tungstenite::connect(format!("ws://127.0.0.1:{}", port)).unwrap();
+    if let tungstenite::stream::MaybeTlsStream::Plain(stream) = socket.get_ref() {
+      let timeout = std::time::Duration::from_secs(5);
+      stream.set_read_timeout(Some(timeout)).unwrap();
+    }
+    socket
+  };
+  let join = |client: &mut Client| -> serde_json::Value {
+    let message = r#"{"type": "join", "room": "table", "name": "Ann"}"#;
+    client
+      .send(tungstenite::Message::Text(String::from(message)))
+      .unwrap();
+    serde_json::from_str(client.read().unwrap().to_text().unwrap()).unwrap()
+  };
+
+  let mut first = connect();
+  assert_eq!(join(&mut first)["type"], "joined");
+  first.close(None).unwrap();
+
+  // a new connection from the same address gets no new allowance
+  let mut second = connect();
+  assert_eq!(join(&mut second)["message"], "too many messages");
+}
@@ -0,0 +1,119 @@
+use super::super::{chi_square, kolmogorov_smirnov, DieLuck, Distribution, Roller, RollerErr};
+use super::{SelfTest, SelfTestCase};
+use rand::Rng;
+use std::collections::BTreeMap;
+
+/// smallest expected count of a chi-square category, smaller ones are merged
+const MIN_EXPECTED: f64 = 5.0;
+
+impl SelfTest {
+  /// Throws every definition, testing each in turn
+  pub fn run(&mut self) -> Result<Vec<SelfTestCase>, RollerErr> {
+    let definitions = self.definitions.clone();
+    definitions.iter().map(|x| self.run_one(x)).collect()
+  }
+
+  fn run_one(&mut self, definition: &str) -> Result<SelfTestCase, RollerErr> {
+    let mut r = definition.parse::<Roller>()?.seed(self.rng.gen());
+    let distribution = r.distribution();
+
+    let mut outcomes: BTreeMap<i16, u64> = BTreeMap::new();
+    let mut faces: BTreeMap<u32, Vec<u64>> = BTreeMap::new();
+    for _ in 0..self.rolls {
+      let result = r.roll();
+      *outcomes.entry(result.outcome).or_default() += 1;
+
+      // acing dice add up into a single result, no longer a fair die
+      if !r.fair_faces() {
+        continue;
+      }
+      for (face, sides) in result.dice.iter().zip(result.sides.iter()) {
+        let counts = faces.entry(*sides).or_insert(vec![0; *sides as usize]);
+        // a face out of range counts as a face of its own, failing the test
+        match counts.get_mut((*face as usize).wrapping_sub(1)) {
+          Some(count) => *count += 1,
+          None => counts.push(1),
+        }
+      }
+    }
+
+    let (impossible, fit, ks) = match &distribution {
+      Some(distribution) => {
+        let (observed, expected, impossible) = bins(&outcomes, distribution);
+        (
+          impossible,
+          Some(chi_square(&observed, &expected)),
+          Some(kolmogorov_smirnov(&outcomes, distribution)),
+        )
+      }
+      None => (0, None, None),
+    };
+
+    Ok(SelfTestCase {
+      definition: String::from(definition),
+      rolls: self.rolls,
+      impossible,
+      fit,
+      ks,
+      faces: faces
+        .into_iter()
+        .map(|(sides, faces)| {
+          let mut expected = vec![1.0 / sides as f64; sides as usize];
+          expected.resize(faces.len(), 0.0);
+          DieLuck {
+            sides,
+            fit: face_fit(&faces, &expected),
+            faces,
+          }
+        })
+        .collect(),
+    })
+  }
+}
+
+/// the chi-square of the faces, failing outright when a face is out of range
+fn face_fit(faces: &[u64], expected: &[f64]) -> super::super::ChiSquare {
+  let mut fit = chi_square(faces, expected);
+  if faces.len() > expected.iter().filter(|x| **x > 0.0).count() {
+    fit.p_value = 0.0;
+  }
+  fit
+}
+
+/// observed counts and expected probabilities of consecutive outcomes, merged until every
+/// category expects enough of them, along with the count of impossible outcomes
+fn bins(outcomes: &BTreeMap<i16, u64>, distribution: &Distribution) -> (Vec<u64>, Vec<f64>, u64) {
+  let rolls: u64 = outcomes.values().sum();
+  let impossible = outcomes
+    .iter()
+    .filter(|(x, _)| distribution.probability(**x) == 0.0)
+    .map(|(_, count)| count)
+    .sum();
+
+  let mut observed: Vec<u64> = Vec::new();
+  let mut expected: Vec<f64> = Vec::new();
+  let (mut count, mut probability) = (0_u64, 0.0);
+  for (outcome, p) in distribution.outcomes() {
+    count += outcomes.get(&outcome).copied().unwrap_or(0);
+    probability += p;
+    if probability * rolls as f64 >= MIN_EXPECTED {
+      observed.push(count);
+      expected.push(probability);
+      (count, probability) = (0, 0.0);
+    }
+  }
+
+  // the tail joins the last category
+  match (observed.last_mut(), expected.last_mut()) {
+    (Some(o), Some(e)) => {
+      *o += count;
+      *e += probability;
+    }
+    _ => {
+      observed.push(count);
+      expected.push(probability);
+    }
+  }
+
+  (observed, expected, impossible)
+}
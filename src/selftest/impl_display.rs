@@ -0,0 +1,55 @@
+use super::SelfTestCase;
+use std::fmt;
+
+/// "4d6 max3: 100000 rolls, chi-square 12.31 (14 df) p 0.58, ks 0.0021 p 0.75 => pass", then a
+/// line per die size with the share of every face
+impl fmt::Display for SelfTestCase {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}: {} rolls", self.definition, self.rolls)?;
+
+    if let Some(fit) = self.fit {
+      write!(
+        f,
+        ", chi-square {:.2} ({} df) p {:.2}",
+        fit.statistic, fit.degrees, fit.p_value
+      )?;
+    }
+    if let Some(ks) = self.ks {
+      write!(f, ", ks {:.4} p {:.2}", ks.statistic, ks.p_value)?;
+    }
+    if self.impossible > 0 {
+      write!(f, ", {} impossible outcomes", self.impossible)?;
+    }
+    if self.fit.is_none() {
+      write!(f, ", no exact distribution")?;
+    }
+
+    match self.passed() {
+      true => write!(f, " => pass")?,
+      false => write!(f, " => FAIL")?,
+    }
+
+    for die in &self.faces {
+      let total: u64 = die.faces.iter().sum();
+      let shares: Vec<String> = die
+        .faces
+        .iter()
+        .enumerate()
+        .map(|(face, count)| format!("{}: {:.2}%", face + 1, *count as f64 * 100.0 / total as f64))
+        .collect();
+
+      write!(
+        f,
+        "\n  d{}: {} dice [{}], chi-square {:.2} ({} df) p {:.2}",
+        die.sides,
+        total,
+        shares.join(", "),
+        die.fit.statistic,
+        die.fit.degrees,
+        die.fit.p_value
+      )?;
+    }
+
+    Ok(())
+  }
+}
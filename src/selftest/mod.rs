@@ -0,0 +1,119 @@
+use super::{ChiSquare, DieLuck, KolmogorovSmirnov};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// public functions implementation
+mod fn_run;
+mod impl_display;
+
+// unit tests
+mod ts_run;
+
+/// definitions thrown by default, one or more per modifier path
+const DEFINITIONS: [&str; 26] = [
+  "1d6",
+  "1d20",
+  "1d100",
+  "1d1000",
+  "3d6+2",
+  "2d6-3",
+  "d8+d10+2d6",
+  "4d6 max3",
+  "4d6 min3",
+  "5d6 mid3",
+  "2d20 max1",
+  "6d10 sc8",
+  "5d10 sc7 rule:=10:2 rule:=1:-1:f",
+  "2d10 sc7 rule:=10:3:m2",
+  "0d10 wod",
+  "d8+d10+2d6 effect",
+  "d6+d10 up:d6 down:d10 effect",
+  "2d6+1 bands:pbta",
+  "2d6+1 tn8",
+  "1d100 under45 crit:brp",
+  "3d6+2d6+1d6 yz",
+  "8d6 sc5 gl1",
+  "3d6 ex6",
+  "5d10 wod",
+  "5d10 sc8 rote",
+  "3d6:base+2d6:skill",
+];
+
+/// tests with a p-value under it fail. Small enough for a fair roller to pass many tests at once
+pub const SIGNIFICANCE: f64 = 1e-6;
+
+#[derive(Debug)]
+/// Throws definitions many times, checking the outcomes against their exact distribution and
+/// every die against a fair one
+pub struct SelfTest {
+  definitions: Vec<String>,
+
+  /// throws per definition
+  rolls: u64,
+
+  /// random number generator, seeding every roller
+  rng: StdRng,
+}
+
+impl Default for SelfTest {
+  fn default() -> Self {
+    Self {
+      definitions: DEFINITIONS.iter().map(|x| String::from(*x)).collect(),
+      rolls: 100_000,
+      rng: StdRng::from_entropy(),
+    }
+  }
+}
+
+impl SelfTest {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Throws the given definitions instead of the built-in ones
+  pub fn definitions(mut self, definitions: Vec<String>) -> Self {
+    self.definitions = definitions;
+    self
+  }
+
+  /// Sets the number of throws per definition
+  pub fn rolls(mut self, rolls: u64) -> Self {
+    self.rolls = rolls;
+    self
+  }
+
+  /// Seeds the random number generator, making the run reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self
+  }
+}
+
+#[derive(Debug, PartialEq)]
+/// How the throws of a definition fit what a fair roller yields
+pub struct SelfTestCase {
+  pub definition: String,
+  pub rolls: u64,
+
+  /// outcomes the exact distribution deems impossible
+  pub impossible: u64,
+
+  /// chi-square test of the outcomes, when the definition has an exact distribution
+  pub fit: Option<ChiSquare>,
+
+  /// Kolmogorov-Smirnov test of the outcomes, when the definition has an exact distribution
+  pub ks: Option<KolmogorovSmirnov>,
+
+  /// face counts and chi-square test per die size, unless dice ace into a single result
+  pub faces: Vec<DieLuck>,
+}
+
+impl SelfTestCase {
+  /// Whether every test of the case passed
+  pub fn passed(&self) -> bool {
+    self.impossible == 0
+      && self.fit.is_none_or(|x| x.p_value >= SIGNIFICANCE)
+      && self.ks.is_none_or(|x| x.p_value >= SIGNIFICANCE)
+      && self.faces.iter().all(|x| x.fit.p_value >= SIGNIFICANCE)
+  }
+}
@@ -0,0 +1,50 @@
+#[test]
+fn built_in_definitions() {
+  let cases = super::SelfTest::new().seed(1).rolls(20_000).run().unwrap();
+  assert_eq!(cases.len(), super::DEFINITIONS.len());
+
+  for case in cases {
+    assert!(case.passed(), "{}", case);
+  }
+}
+
+#[test]
+#[ignore = "a million throws per definition, run with cargo test --release -- --ignored"]
+fn million_throws() {
+  let cases = super::SelfTest::new().rolls(1_000_000).run().unwrap();
+
+  for case in cases {
+    assert!(case.passed(), "{}", case);
+  }
+}
+
+#[test]
+fn every_path_tested() {
+  let cases = super::SelfTest::new().seed(1).rolls(100).run().unwrap();
+
+  let inexact: Vec<&str> = cases
+    .iter()
+    .filter(|x| x.fit.is_none())
+    .map(|x| x.definition.as_str())
+    .collect();
+  assert_eq!(inexact, vec!["3d6 ex6", "5d10 wod", "5d10 sc8 rote"]);
+  assert!(cases.iter().all(|x| !x.faces.is_empty()));
+}
+
+#[test]
+fn unknown_definition() {
+  let r = super::SelfTest::new()
+    .definitions(vec![String::from("fast")])
+    .run();
+  assert_eq!(r.unwrap_err(), super::super::RollerErr::Generic);
+}
+
+#[test]
+fn acing_dice() {
+  let cases = super::SelfTest::new()
+    .definitions(vec![String::from("trait d8 wild d6"), String::from("7k3")])
+    .rolls(100)
+    .run()
+    .unwrap();
+  assert!(cases.iter().all(|x| x.faces.is_empty() && x.fit.is_none()));
+}
@@ -0,0 +1,39 @@
+use super::fn_gamma::upper_gamma;
+use super::ChiSquare;
+
+/// Tests observed counts against the expected probability of every category
+pub fn chi_square(observed: &[u64], expected: &[f64]) -> ChiSquare {
+  let total: u64 = observed.iter().sum();
+
+  let statistic = observed
+    .iter()
+    .zip(expected)
+    .filter(|(_, p)| **p > 0.0)
+    .map(|(o, p)| {
+      let e = p * total as f64;
+      (*o as f64 - e).powi(2) / e
+    })
+    .sum();
+  let degrees = expected
+    .iter()
+    .filter(|x| **x > 0.0)
+    .count()
+    .saturating_sub(1) as u32;
+
+  ChiSquare {
+    statistic,
+    degrees,
+    p_value: match degrees {
+      0 => 1.0,
+      _ => upper_gamma(degrees as f64 / 2.0, statistic / 2.0),
+    },
+  }
+}
+
+/// How many standard deviations the value lies from the mean
+pub fn z_score(value: f64, mean: f64, std_dev: f64) -> f64 {
+  match std_dev > 0.0 {
+    true => (value - mean) / std_dev,
+    false => 0.0,
+  }
+}
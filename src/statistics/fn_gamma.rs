@@ -0,0 +1,83 @@
+/// iterations before giving up on convergence
+const MAX_ITERATIONS: usize = 1000;
+
+const EPSILON: f64 = 1e-14;
+
+/// the regularized upper incomplete gamma function Q(a, x)
+pub(super) fn upper_gamma(a: f64, x: f64) -> f64 {
+  if x <= 0.0 {
+    return 1.0;
+  }
+
+  match x < a + 1.0 {
+    true => 1.0 - lower_series(a, x),
+    false => upper_fraction(a, x),
+  }
+}
+
+/// P(a, x) by its series, converging fast for x < a + 1
+fn lower_series(a: f64, x: f64) -> f64 {
+  let mut term = 1.0 / a;
+  let mut sum = term;
+  for n in 1..MAX_ITERATIONS {
+    term *= x / (a + n as f64);
+    sum += term;
+    if term.abs() < sum.abs() * EPSILON {
+      break;
+    }
+  }
+
+  sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+/// Q(a, x) by its continued fraction, Lentz's method, converging fast for x >= a + 1
+fn upper_fraction(a: f64, x: f64) -> f64 {
+  let tiny = f64::MIN_POSITIVE / EPSILON;
+
+  let mut b = x + 1.0 - a;
+  let mut c = 1.0 / tiny;
+  let mut d = 1.0 / b;
+  let mut h = d;
+  for n in 1..MAX_ITERATIONS {
+    let an = -(n as f64) * (n as f64 - a);
+    b += 2.0;
+    d = an * d + b;
+    if d.abs() < tiny {
+      d = tiny;
+    }
+    c = b + an / c;
+    if c.abs() < tiny {
+      c = tiny;
+    }
+    d = 1.0 / d;
+    let delta = d * c;
+    h *= delta;
+    if (delta - 1.0).abs() < EPSILON {
+      break;
+    }
+  }
+
+  (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+/// ln Γ(x) by the Lanczos approximation
+fn ln_gamma(x: f64) -> f64 {
+  const COEFFICIENTS: [f64; 6] = [
+    76.180_091_729_471_46,
+    -86.505_320_329_416_77,
+    24.014_098_240_830_91,
+    -1.231_739_572_450_155,
+    0.001_208_650_973_866_179,
+    -0.000_005_395_239_384_953,
+  ];
+
+  let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+  let series = COEFFICIENTS
+    .iter()
+    .enumerate()
+    .fold(1.000_000_000_190_015, |sum, (i, c)| {
+      sum + c / (x + 1.0 + i as f64)
+    });
+
+  -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
@@ -0,0 +1,61 @@
+use super::super::Distribution;
+use super::KolmogorovSmirnov;
+use std::collections::BTreeMap;
+
+/// terms of the Kolmogorov series summed at most
+const MAX_TERMS: u32 = 100;
+
+/// Tests observed outcomes, counted per outcome, against an exact distribution. The test is
+/// conservative on discrete outcomes such as dice: a fair roller passes more often than the p-value
+/// says
+pub fn kolmogorov_smirnov(
+  observed: &BTreeMap<i16, u64>,
+  distribution: &Distribution,
+) -> KolmogorovSmirnov {
+  let total: u64 = observed.values().sum();
+  if total == 0 {
+    return KolmogorovSmirnov {
+      statistic: 0.0,
+      p_value: 1.0,
+    };
+  }
+
+  // every outcome possible or seen
+  let mut outcomes: Vec<i16> = distribution.outcomes().map(|(x, _)| x).collect();
+  outcomes.extend(observed.keys());
+  outcomes.sort();
+  outcomes.dedup();
+
+  let statistic = outcomes
+    .iter()
+    .map(|x| {
+      let seen: u64 = observed.range(..=x).map(|(_, count)| count).sum();
+      (seen as f64 / total as f64 - distribution.at_most(*x)).abs()
+    })
+    .fold(0.0, f64::max);
+
+  let n = (total as f64).sqrt();
+  KolmogorovSmirnov {
+    statistic,
+    p_value: kolmogorov((n + 0.12 + 0.11 / n) * statistic),
+  }
+}
+
+/// the chance of the Kolmogorov distribution exceeding lambda
+fn kolmogorov(lambda: f64) -> f64 {
+  if lambda < 0.2 {
+    return 1.0;
+  }
+
+  let mut sum = 0.0;
+  for k in 1..=MAX_TERMS {
+    let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+    let term = sign * (-2.0 * (k as f64 * lambda).powi(2)).exp();
+    sum += term;
+    if term.abs() < 1e-12 {
+      break;
+    }
+  }
+
+  (2.0 * sum).clamp(0.0, 1.0)
+}
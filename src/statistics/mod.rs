@@ -0,0 +1,31 @@
+// public functions implementation
+mod fn_chi_square;
+mod fn_gamma;
+mod fn_kolmogorov;
+
+// unit tests
+mod ts_chi_square;
+mod ts_kolmogorov;
+
+pub use fn_chi_square::*;
+pub use fn_kolmogorov::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A chi-square goodness of fit test
+pub struct ChiSquare {
+  pub statistic: f64,
+  pub degrees: u32,
+
+  /// the chance of a fit at least this bad from fair dice. Tiny values hint at bias
+  pub p_value: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A Kolmogorov-Smirnov test, comparing observed outcomes to a distribution
+pub struct KolmogorovSmirnov {
+  /// the largest gap between the observed and the expected chance of rolling at most an outcome
+  pub statistic: f64,
+
+  /// the chance of a gap at least this large from a fair roller
+  pub p_value: f64,
+}
@@ -0,0 +1,28 @@
+#[test]
+fn perfect_fit() {
+  let c = super::chi_square(&[10, 10, 10, 10, 10, 10], &[1.0 / 6.0; 6]);
+  assert!(c.statistic.abs() < 1e-9);
+  assert_eq!(c.degrees, 5);
+  assert!((c.p_value - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn p_value() {
+  // 11.07 is the 95th percentile of a chi-square with 5 degrees of freedom
+  let c = super::chi_square(&[0, 0, 0, 0, 0, 60], &[1.0 / 6.0; 6]);
+  assert!((c.statistic - 300.0).abs() < 1e-9);
+  assert!(c.p_value < 1e-9);
+
+  let p = super::fn_gamma::upper_gamma(2.5, 11.07 / 2.0);
+  assert!((p - 0.05).abs() < 1e-3);
+
+  // 3.84 is the 95th percentile with 1 degree of freedom
+  let p = super::fn_gamma::upper_gamma(0.5, 3.84 / 2.0);
+  assert!((p - 0.05).abs() < 1e-3);
+}
+
+#[test]
+fn z_score() {
+  assert_eq!(super::z_score(12.0, 10.0, 2.0), 1.0);
+  assert_eq!(super::z_score(12.0, 10.0, 0.0), 0.0);
+}
@@ -0,0 +1,17 @@
+#[test]
+fn perfect_fit() {
+  let d = super::super::Distribution::uniform(4);
+  let observed = std::collections::BTreeMap::from([(1, 25), (2, 25), (3, 25), (4, 25)]);
+  let ks = super::kolmogorov_smirnov(&observed, &d);
+  assert!(ks.statistic.abs() < 1e-9);
+  assert!((ks.p_value - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn biased() {
+  let d = super::super::Distribution::uniform(4);
+  let observed = std::collections::BTreeMap::from([(1, 1000), (4, 1000)]);
+  let ks = super::kolmogorov_smirnov(&observed, &d);
+  assert!((ks.statistic - 0.25).abs() < 1e-9);
+  assert!(ks.p_value < 1e-9);
+}
@@ -0,0 +1,9 @@
+use super::super::super::RollResult;
+use super::Blades;
+
+impl Blades {
+  /// Makes the action roll. The outcome is the die kept, the band its result
+  pub fn roll(&mut self) -> RollResult {
+    self.roller.roll()
+  }
+}
@@ -0,0 +1,40 @@
+use super::super::{Band, Roller};
+
+// public functions implementation
+mod fn_roll;
+
+// unit tests
+mod ts_roll;
+
+#[derive(Debug)]
+/// Blades in the Dark action rolls: Nd6 keeping the highest. 6 is a success, 4-5 a partial and
+/// two 6s a critical. With no dice, 2d6 keeping the lowest, which can't be a critical
+pub struct Blades {
+  roller: Roller,
+}
+
+impl Blades {
+  pub fn new(dice: u32) -> Self {
+    let bands = vec![
+      Band::new(6, 6, "success"),
+      Band::new(4, 5, "partial"),
+      Band::new(1, 3, "failure"),
+    ];
+
+    let mut critical = vec![Band::new(6, 6, "critical").matching(2)];
+    critical.extend(bands.clone());
+
+    Self {
+      roller: Roller::new(dice, 6)
+        .take_max(Some(1))
+        .bands(critical)
+        .fallback(1, Roller::new(2, 6).take_min(Some(1)).bands(bands)),
+    }
+  }
+
+  /// Seeds the random number generator, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.roller = self.roller.seed(seed);
+    self
+  }
+}
@@ -0,0 +1,28 @@
+#[test]
+fn action() {
+  let mut r = super::Blades::new(3);
+  for _ in 1..=1000 {
+    let result = r.roll();
+    assert_eq!(result.dice.len(), 3);
+    assert_eq!(result.outcome, *result.dice.iter().max().unwrap() as i16);
+    let sixes = result.dice.iter().filter(|x| **x == 6).count();
+    let band = match (result.outcome, sixes) {
+      (6, 2..) => "critical",
+      (6, _) => "success",
+      (4..=5, _) => "partial",
+      _ => "failure",
+    };
+    assert_eq!(result.band.as_deref(), Some(band));
+  }
+}
+
+#[test]
+fn zero_dice() {
+  let mut r = super::Blades::new(0);
+  for _ in 1..=1000 {
+    let result = r.roll();
+    assert_eq!(result.dice.len(), 2);
+    assert_eq!(result.outcome, *result.dice.iter().min().unwrap() as i16);
+    assert_ne!(result.band.as_deref(), Some("critical"));
+  }
+}
@@ -0,0 +1,9 @@
+use super::super::super::RollResult;
+use super::Exalted;
+
+impl Exalted {
+  /// Makes the roll. The outcome is the number of successes
+  pub fn roll(&mut self) -> RollResult {
+    self.roller.roll()
+  }
+}
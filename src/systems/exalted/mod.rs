@@ -0,0 +1,41 @@
+use super::super::{Comparison, Roller, SuccessRule};
+
+// public functions implementation
+mod fn_roll;
+
+// unit tests
+mod ts_roll;
+
+#[derive(Debug)]
+/// Exalted dice pools: Nd10 where 7 or more is a success and 10 counts twice. Damage pools count
+/// 10 once
+pub struct Exalted {
+  roller: Roller,
+
+  dice: u32,
+}
+
+impl Exalted {
+  pub fn new(dice: u32) -> Self {
+    Self {
+      roller: Roller::new(dice, 10)
+        .success_threshold(Some(7))
+        .success_rule(SuccessRule::new(Comparison::Equal, 10, 2)),
+      dice,
+    }
+  }
+
+  /// Makes it a damage roll, where 10 counts once
+  pub fn damage(mut self, damage: bool) -> Self {
+    if damage {
+      self.roller = Roller::new(self.dice, 10).success_threshold(Some(7));
+    }
+    self
+  }
+
+  /// Seeds the random number generator, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.roller = self.roller.seed(seed);
+    self
+  }
+}
@@ -0,0 +1,15 @@
+#[test]
+fn successes() {
+  let mut r = super::Exalted::new(8);
+  let mut damage = super::Exalted::new(8).damage(true);
+  for _ in 1..=1000 {
+    let result = r.roll();
+    let successes = result.dice.iter().filter(|x| **x >= 7).count();
+    let tens = result.dice.iter().filter(|x| **x == 10).count();
+    assert_eq!(result.outcome, (successes + tens) as i16);
+
+    let result = damage.roll();
+    let successes = result.dice.iter().filter(|x| **x >= 7).count();
+    assert_eq!(result.outcome, successes as i16);
+  }
+}
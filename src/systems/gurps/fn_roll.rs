@@ -0,0 +1,9 @@
+use super::super::super::RollResult;
+use super::Gurps;
+
+impl Gurps {
+  /// Makes the success roll. The margin is how far under the skill it went
+  pub fn roll(&mut self) -> RollResult {
+    self.roller.roll()
+  }
+}
@@ -0,0 +1,30 @@
+use super::super::{Criticals, Roller};
+
+// public functions implementation
+mod fn_roll;
+
+// unit tests
+mod ts_roll;
+
+#[derive(Debug)]
+/// GURPS success rolls: 3d6 at or under the effective skill, with the GURPS critical table. 3-4
+/// always succeed and 17-18 always fail
+pub struct Gurps {
+  roller: Roller,
+}
+
+impl Gurps {
+  pub fn new(skill: i16) -> Self {
+    Self {
+      roller: Roller::new(3, 6)
+        .under(Some(skill))
+        .criticals(Some(Criticals::gurps())),
+    }
+  }
+
+  /// Seeds the random number generator, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.roller = self.roller.seed(seed);
+    self
+  }
+}
@@ -0,0 +1,32 @@
+#[test]
+fn success_roll() {
+  let mut r = super::Gurps::new(12);
+  for _ in 1..=1000 {
+    let result = r.roll();
+    assert_eq!(result.margin(), Some(12 - result.outcome));
+    assert_eq!(result.raises(), None);
+    let critical = match result.outcome {
+      3..=4 => vec![super::super::super::RollFlag::CriticalSuccess],
+      17..=18 => vec![super::super::super::RollFlag::CriticalFailure],
+      _ => vec![],
+    };
+    assert_eq!(result.flags, critical);
+  }
+}
+
+#[test]
+fn automatic() {
+  // 17 and 18 fail however high the skill, 3 and 4 succeed however low
+  for skill in [2, 12, 17, 18, 20] {
+    let mut r = super::Gurps::new(skill);
+    for _ in 1..=1000 {
+      let result = r.roll();
+      let success = match result.outcome {
+        3..=4 => true,
+        17..=18 => false,
+        outcome => outcome <= skill,
+      };
+      assert_eq!(result.success(), Some(success));
+    }
+  }
+}
@@ -0,0 +1,9 @@
+mod blades;
+mod exalted;
+mod gurps;
+mod shadowrun;
+
+pub use blades::*;
+pub use exalted::*;
+pub use gurps::*;
+pub use shadowrun::*;
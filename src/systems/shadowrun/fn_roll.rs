@@ -0,0 +1,14 @@
+use super::super::super::RollResult;
+use super::{Edge, Shadowrun};
+
+impl Shadowrun {
+  /// Makes the test. The outcome is the number of hits
+  pub fn roll(&mut self) -> RollResult {
+    let result = self.roller.roll();
+
+    match self.edge {
+      Some(Edge::SecondChance) => self.roller.reroll(&result, |x| x < 5),
+      _ => result,
+    }
+  }
+}
@@ -0,0 +1,53 @@
+use super::super::Roller;
+
+// public functions implementation
+mod fn_roll;
+
+// unit tests
+mod ts_roll;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The ways edge can be spent on a Shadowrun test
+pub enum Edge {
+  /// push the limit: 6s explode
+  RuleOfSix,
+
+  /// second chance: dice without a hit are rerolled once
+  SecondChance,
+}
+
+#[derive(Debug)]
+/// Shadowrun tests: a pool of d6 where 5 and 6 are hits. More than half the pool showing 1 is a
+/// glitch, a critical glitch with no hits
+pub struct Shadowrun {
+  roller: Roller,
+
+  edge: Option<Edge>,
+}
+
+impl Shadowrun {
+  pub fn new(dice: u32) -> Self {
+    Self {
+      roller: Roller::new(dice, 6)
+        .success_threshold(Some(5))
+        .glitch(Some(1)),
+      edge: None,
+    }
+  }
+
+  /// Spends edge on the test
+  pub fn edge(mut self, edge: Option<Edge>) -> Self {
+    self.roller = self.roller.explode_threshold(match edge {
+      Some(Edge::RuleOfSix) => Some(6),
+      _ => None,
+    });
+    self.edge = edge;
+    self
+  }
+
+  /// Seeds the random number generator, making the rolls reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.roller = self.roller.seed(seed);
+    self
+  }
+}
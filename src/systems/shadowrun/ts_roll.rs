@@ -0,0 +1,54 @@
+#[test]
+fn hits() {
+  let mut r = super::Shadowrun::new(8);
+  for _ in 1..=1000 {
+    let result = r.roll();
+    assert_eq!(result.dice.len(), 8);
+    let hits = result.dice.iter().filter(|x| **x >= 5).count() as i16;
+    assert_eq!(result.outcome, hits);
+  }
+}
+
+#[test]
+fn glitches() {
+  let mut r = super::Shadowrun::new(3);
+  for _ in 1..=1000 {
+    let result = r.roll();
+    let ones = result.dice.iter().filter(|x| **x == 1).count();
+    let expected = match (ones >= 2, result.outcome) {
+      (false, _) => vec![],
+      (true, 0) => vec![super::super::super::RollFlag::CriticalGlitch],
+      (true, _) => vec![super::super::super::RollFlag::Glitch],
+    };
+    assert_eq!(result.flags, expected);
+  }
+}
+
+#[test]
+fn rule_of_six() {
+  let mut r = super::Shadowrun::new(6).edge(Some(super::Edge::RuleOfSix));
+  for _ in 1..=1000 {
+    let result = r.roll();
+    let sixes = result.dice.iter().filter(|x| **x == 6).count();
+    assert_eq!(result.dice.len(), 6 + sixes);
+
+    // exploded dice count towards the glitch as well
+    let ones = result.dice.iter().filter(|x| **x == 1).count();
+    assert_eq!(result.flags.is_empty(), ones * 2 <= result.dice.len());
+  }
+}
+
+#[test]
+fn second_chance() {
+  let mut r = super::Shadowrun::new(6)
+    .edge(Some(super::Edge::SecondChance))
+    .seed(7);
+  let mut hits = 0;
+  for _ in 1..=1000 {
+    let result = r.roll();
+    assert_eq!(result.dice.len(), 6);
+    hits += result.outcome;
+  }
+  // two chances at a third each is a bit over half the dice
+  assert!(hits > 3000);
+}
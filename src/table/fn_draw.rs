@@ -0,0 +1,84 @@
+use super::super::{RollResult, Roller};
+use super::{TableErr, TableResult, Tables};
+use rand::Rng;
+
+impl Tables {
+  /// Draws distinct entries from the named table, picking among the entries not drawn yet as
+  /// likely as the roll of the table is to show them. Drawn entries stay exhausted until the
+  /// table is reset
+  pub fn draw(&mut self, name: &str, count: usize) -> Result<Vec<TableResult>, TableErr> {
+    let table = self
+      .get(name)
+      .ok_or(TableErr::UnknownTable(String::from(name)))?;
+    let mut remaining: Vec<&str> = table
+      .entries
+      .iter()
+      .map(|x| x.text.as_str())
+      .filter(|x| !self.is_exhausted(name, x))
+      .collect();
+    remaining.sort();
+    remaining.dedup();
+    if remaining.len() < count {
+      return Err(TableErr::Exhausted);
+    }
+
+    (0..count).map(|_| self.draw_one(name)).collect()
+  }
+
+  fn draw_one(&mut self, name: &str) -> Result<TableResult, TableErr> {
+    let table = self
+      .get(name)
+      .ok_or(TableErr::UnknownTable(String::from(name)))?;
+    let roller: Roller = table.roll.parse().map_err(|_| TableErr::InvalidRoll)?;
+    let left = |value: i16| {
+      table
+        .entry(value)
+        .is_some_and(|x| !self.is_exhausted(name, &x.text))
+    };
+
+    // every value of the entries left, as likely as the roll is to show it. Without an exact
+    // distribution, every value is as likely
+    let weighted: Vec<(i16, f64)> = match roller.distribution() {
+      Some(distribution) => distribution.outcomes().filter(|x| left(x.0)).collect(),
+      None => table
+        .entries
+        .iter()
+        .flat_map(|x| x.low..=x.high)
+        .filter(|x| left(*x))
+        .map(|x| (x, 1.0))
+        .collect(),
+    };
+    let total: f64 = weighted.iter().map(|x| x.1).sum();
+    let Some(last) = weighted.last().map(|x| x.0).filter(|_| total > 0.0) else {
+      return Err(TableErr::Exhausted);
+    };
+
+    let mut pick = self.rng.gen_range(0.0..total);
+    let mut value = last;
+    for (outcome, probability) in weighted {
+      if pick < probability {
+        value = outcome;
+        break;
+      }
+      pick -= probability;
+    }
+
+    let text = self
+      .get(name)
+      .and_then(|x| x.lookup(value))
+      .map(String::from)
+      .ok_or(TableErr::NoEntry(value))?;
+    self
+      .exhausted
+      .entry(String::from(name))
+      .or_default()
+      .insert(text.clone());
+    let text = self.resolve_text(&text, 0)?;
+
+    Ok(TableResult {
+      table: String::from(name),
+      roll: RollResult::new(Vec::new(), value),
+      text,
+    })
+  }
+}
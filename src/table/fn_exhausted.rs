@@ -0,0 +1,41 @@
+use super::{TableErr, Tables};
+use std::fs;
+use std::path::Path;
+
+impl Tables {
+  /// Whether the entry of the named table has already been drawn
+  pub fn is_exhausted(&self, name: &str, text: &str) -> bool {
+    self
+      .exhausted
+      .get(name)
+      .map(|x| x.contains(text))
+      .unwrap_or(false)
+  }
+
+  /// Makes every entry of the named table drawable again
+  pub fn reset(&mut self, name: &str) {
+    self.exhausted.remove(name);
+  }
+
+  /// Loads the entries drawn in previous runs. A missing file means nothing was drawn yet
+  pub fn load_exhausted(&mut self, path: &Path) -> Result<(), TableErr> {
+    if !path.exists() {
+      return Ok(());
+    }
+
+    let content = fs::read_to_string(path).map_err(|_| TableErr::Io)?;
+    self.exhausted = serde_json::from_str(&content).map_err(|_| TableErr::Parse)?;
+
+    Ok(())
+  }
+
+  /// Saves the drawn entries, so that following runs won't draw them again
+  pub fn save_exhausted(&self, path: &Path) -> Result<(), TableErr> {
+    if let Some(directory) = path.parent() {
+      fs::create_dir_all(directory).map_err(|_| TableErr::Io)?;
+    }
+
+    let content = serde_json::to_string_pretty(&self.exhausted).map_err(|_| TableErr::Parse)?;
+    fs::write(path, content).map_err(|_| TableErr::Io)
+  }
+}
@@ -0,0 +1,185 @@
+use super::super::Roller;
+use super::{Table, TableEntry, TableErr, Tables};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize)]
+/// layout of a TOML table file
+struct TableFile {
+  name: Option<String>,
+  roll: Option<String>,
+  entries: Vec<String>,
+}
+
+/// an entry as written in the file, either with a range "01-15" or with a weight "x3"
+enum Line {
+  Ranged(TableEntry),
+  Weighted(u32, String),
+}
+
+impl Table {
+  /// Loads a table named after the file. ".csv" and ".md" files are read as CSV and Markdown,
+  /// anything else as TOML
+  pub fn load(path: &Path) -> Result<Table, TableErr> {
+    let content = fs::read_to_string(path).map_err(|_| TableErr::Io)?;
+    let name = path
+      .file_stem()
+      .map(|x| x.to_string_lossy().to_string())
+      .unwrap_or_default();
+
+    match path.extension() {
+      Some(extension) if extension == "csv" => Table::from_csv(&name, &content),
+      Some(extension) if extension == "md" => Table::from_markdown(&name, &content),
+      _ => Table::from_toml(&name, &content),
+    }
+  }
+
+  /// roll = "d100"
+  /// entries = ["01-15: goblins", "16-100: 2d4 wolves"]
+  ///
+  /// Weighted tables need no roll: entries = ["x3: goblins", "wolves"]
+  pub fn from_toml(name: &str, content: &str) -> Result<Table, TableErr> {
+    let file: TableFile = toml::from_str(content).map_err(|_| TableErr::Parse)?;
+
+    let lines = file
+      .entries
+      .iter()
+      .map(|x| match x.split_once(':') {
+        Some((range, text)) => Table::parse_line(range, text),
+        None => Ok(Line::Weighted(1, String::from(x.trim()))),
+      })
+      .collect::<Result<Vec<Line>, TableErr>>()?;
+
+    let name = file.name.unwrap_or(String::from(name));
+    Table::new(&name, file.roll.as_deref(), lines)
+  }
+
+  /// The header holds the roll definition in the first column. Weighted tables ignore it
+  ///
+  /// d100,monster
+  /// 01-15,goblins
+  pub fn from_csv(name: &str, content: &str) -> Result<Table, TableErr> {
+    let rows: Vec<Vec<&str>> = content
+      .lines()
+      .filter(|x| !x.trim().is_empty())
+      .map(|x| x.splitn(2, ',').collect())
+      .collect();
+
+    Table::from_rows(name, rows)
+  }
+
+  /// The header holds the roll definition in the first column. Weighted tables ignore it
+  ///
+  /// | d100  | monster |
+  /// |-------|---------|
+  /// | 01-15 | goblins |
+  pub fn from_markdown(name: &str, content: &str) -> Result<Table, TableErr> {
+    let rows: Vec<Vec<&str>> = content
+      .lines()
+      .map(|x| x.trim())
+      .filter(|x| x.starts_with('|'))
+      .filter(|x| !x.chars().all(|c| "|-: ".contains(c)))
+      .map(|x| x.trim_matches('|').splitn(2, '|').collect())
+      .collect();
+
+    Table::from_rows(name, rows)
+  }
+
+  fn from_rows(name: &str, rows: Vec<Vec<&str>>) -> Result<Table, TableErr> {
+    let (header, rows) = rows.split_first().ok_or(TableErr::Parse)?;
+
+    let lines = rows
+      .iter()
+      .map(|x| match x.as_slice() {
+        [range, text] => Table::parse_line(range, text),
+        [text] => Ok(Line::Weighted(1, String::from(text.trim()))),
+        _ => Err(TableErr::Parse),
+      })
+      .collect::<Result<Vec<Line>, TableErr>>()?;
+
+    Table::new(name, Some(header[0].trim()), lines)
+  }
+
+  /// Weighted entries are turned into consecutive ranges, rolled with a single die as large as
+  /// the sum of the weights. Ranged entries need a valid roll definition
+  fn new(name: &str, roll: Option<&str>, lines: Vec<Line>) -> Result<Table, TableErr> {
+    let weighted = !lines.is_empty() && lines.iter().all(|x| matches!(x, Line::Weighted(..)));
+
+    let mut entries: Vec<TableEntry> = Vec::new();
+    for line in lines {
+      match line {
+        Line::Ranged(entry) if !weighted => entries.push(entry),
+        Line::Weighted(weight, text) if weighted => {
+          let low = entries.last().map(|x| x.high + 1).unwrap_or(1);
+          let high = low.checked_add(weight as i16 - 1).ok_or(TableErr::Parse)?;
+          entries.push(TableEntry { low, high, text });
+        }
+        _ => return Err(TableErr::Parse),
+      }
+    }
+
+    let roll = match weighted {
+      true => format!("d{}", entries.last().map(|x| x.high).unwrap_or(1)),
+      false => String::from(roll.ok_or(TableErr::InvalidRoll)?),
+    };
+    roll.parse::<Roller>().map_err(|_| TableErr::InvalidRoll)?;
+
+    Ok(Table {
+      name: String::from(name),
+      roll,
+      entries,
+    })
+  }
+
+  /// "01-15", "7" or a weight "x3"
+  fn parse_line(range: &str, text: &str) -> Result<Line, TableErr> {
+    let range = range.trim();
+    let text = String::from(text.trim());
+
+    if let Some(weight) = range.strip_prefix('x') {
+      let weight: u32 = weight.parse().map_err(|_| TableErr::Parse)?;
+      if weight == 0 || weight > i16::MAX as u32 {
+        return Err(TableErr::Parse);
+      }
+      return Ok(Line::Weighted(weight, text));
+    }
+
+    let (low, high) = match range.split_once('-') {
+      Some((low, high)) => (low, high),
+      None => (range, range),
+    };
+
+    Ok(Line::Ranged(TableEntry {
+      low: low.trim().parse().map_err(|_| TableErr::Parse)?,
+      high: high.trim().parse().map_err(|_| TableErr::Parse)?,
+      text,
+    }))
+  }
+}
+
+impl Tables {
+  /// Loads every table file found in the directory, skipping the files that fail to load. Those
+  /// are returned along with their error
+  pub fn load_dir(&mut self, path: &Path) -> Result<Vec<(PathBuf, TableErr)>, TableErr> {
+    let entries = fs::read_dir(path).map_err(|_| TableErr::Io)?;
+
+    let mut skipped = Vec::new();
+    for entry in entries {
+      let Ok(entry) = entry else {
+        continue;
+      };
+      let path = entry.path();
+      if !path.is_file() {
+        continue;
+      }
+
+      match Table::load(&path) {
+        Ok(table) => self.insert(table),
+        Err(err) => skipped.push((path, err)),
+      }
+    }
+
+    Ok(skipped)
+  }
+}
@@ -0,0 +1,98 @@
+use super::super::{Roller, RollerErr};
+use super::{Table, TableEntry, TableErr, TableResult, Tables};
+use rand::Rng;
+
+/// maximum nesting of tables referencing other tables
+const MAX_DEPTH: u8 = 16;
+
+impl Table {
+  /// Returns the text of the entry covering the value
+  pub fn lookup(&self, value: i16) -> Option<&str> {
+    self.entry(value).map(|x| x.text.as_str())
+  }
+
+  pub(super) fn entry(&self, value: i16) -> Option<&TableEntry> {
+    self
+      .entries
+      .iter()
+      .find(|x| x.low <= value && value <= x.high)
+  }
+}
+
+impl Tables {
+  /// Rolls on the named table, rolling inline dice and nested tables of the entry as well
+  pub fn roll(&mut self, name: &str) -> Result<TableResult, TableErr> {
+    self.roll_nested(name, 0)
+  }
+
+  fn roll_nested(&mut self, name: &str, depth: u8) -> Result<TableResult, TableErr> {
+    if depth > MAX_DEPTH {
+      return Err(TableErr::Recursion);
+    }
+
+    let definition = self
+      .get(name)
+      .ok_or(TableErr::UnknownTable(String::from(name)))?
+      .roll
+      .clone();
+    let roll = self
+      .roller(&definition)
+      .map_err(|_| TableErr::InvalidRoll)?
+      .roll();
+
+    let text = self
+      .get(name)
+      .and_then(|x| x.lookup(roll.outcome))
+      .map(String::from)
+      .ok_or(TableErr::NoEntry(roll.outcome))?;
+    let text = self.resolve_text(&text, depth)?;
+
+    Ok(TableResult {
+      table: String::from(name),
+      roll,
+      text,
+    })
+  }
+
+  /// a roller seeded by the tables random number generator
+  pub(super) fn roller(&mut self, definition: &str) -> Result<Roller, RollerErr> {
+    Ok(definition.parse::<Roller>()?.seed(self.rng.gen()))
+  }
+
+  /// rolls inline dice "2d4 goblins" and nested tables "[[weather]]"
+  pub(super) fn resolve_text(&mut self, text: &str, depth: u8) -> Result<String, TableErr> {
+    let mut output = String::new();
+    let mut rest = text;
+
+    while let Some((before, after)) = rest.split_once("[[") {
+      let (nested, after) = after.split_once("]]").ok_or(TableErr::Parse)?;
+      output.push_str(&self.roll_inline_dice(before));
+      output.push_str(&self.roll_nested(nested.trim(), depth + 1)?.text);
+      rest = after;
+    }
+    output.push_str(&self.roll_inline_dice(rest));
+
+    Ok(output)
+  }
+
+  /// replaces every word such as "2d4" or "d6+1" with its outcome
+  fn roll_inline_dice(&mut self, text: &str) -> String {
+    text
+      .split(&[' '])
+      .map(|x| {
+        let is_dice = x.contains('d')
+          && x
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == 'd' || c == '+' || c == '-');
+        match is_dice {
+          false => String::from(x),
+          true => match self.roller(x) {
+            Ok(mut roller) => roller.roll().outcome.to_string(),
+            Err(_) => String::from(x),
+          },
+        }
+      })
+      .collect::<Vec<String>>()
+      .join(" ")
+  }
+}
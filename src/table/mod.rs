@@ -0,0 +1,93 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+// public functions implementation
+mod fn_draw;
+mod fn_exhausted;
+mod fn_load;
+mod fn_roll;
+
+// unit tests
+mod ts_draw;
+mod ts_load;
+mod ts_roll;
+
+mod table_err;
+mod table_result;
+
+pub use table_err::*;
+pub use table_result::*;
+
+#[derive(Debug, PartialEq)]
+/// A random table. The roll definition, such as "d100" or "2d6", picks the entry to look up
+pub struct Table {
+  /// the table name, used by other tables to reference it as "[[name]]"
+  pub name: String,
+
+  /// the definition of the lookup roll
+  pub roll: String,
+
+  /// ranged entries
+  pub entries: Vec<TableEntry>,
+}
+
+#[derive(Debug, PartialEq)]
+/// A table entry, picked when the lookup roll is between low and high
+pub struct TableEntry {
+  pub low: i16,
+  pub high: i16,
+
+  /// the entry text. May hold inline dice "2d4 goblins" and nested tables "[[weather]]"
+  pub text: String,
+}
+
+#[derive(Debug)]
+/// A set of tables, allowing entries to reference one another
+pub struct Tables {
+  tables: HashMap<String, Table>,
+
+  /// entries already drawn, per table
+  exhausted: BTreeMap<String, BTreeSet<String>>,
+
+  /// random number generator, seeding every lookup roll
+  rng: StdRng,
+}
+
+impl Default for Tables {
+  fn default() -> Self {
+    Self {
+      tables: HashMap::new(),
+      exhausted: BTreeMap::new(),
+      rng: StdRng::from_entropy(),
+    }
+  }
+}
+
+impl Tables {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Seeds the random number generator, making rolls and draws reproducible
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.rng = StdRng::seed_from_u64(seed);
+    self
+  }
+
+  /// Adds a table, replacing any table with the same name
+  pub fn insert(&mut self, table: Table) {
+    self.tables.insert(table.name.clone(), table);
+  }
+
+  pub fn get(&self, name: &str) -> Option<&Table> {
+    self.tables.get(name)
+  }
+
+  /// The name of every table, sorted
+  pub fn names(&self) -> Vec<&str> {
+    let mut names: Vec<&str> = self.tables.keys().map(|x| x.as_str()).collect();
+    names.sort();
+    names
+  }
+}
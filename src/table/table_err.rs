@@ -0,0 +1,11 @@
+#[derive(Debug, PartialEq)]
+/// Error returned while loading or rolling on a table
+pub enum TableErr {
+  Io,
+  Parse,
+  InvalidRoll,
+  UnknownTable(String),
+  NoEntry(i16),
+  Recursion,
+  Exhausted,
+}
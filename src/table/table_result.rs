@@ -0,0 +1,15 @@
+use super::super::RollResult;
+use serde::Serialize;
+
+/// A struct holding the result of a table lookup
+#[derive(Debug, Serialize)]
+pub struct TableResult {
+  /// the table rolled on
+  pub table: String,
+
+  /// the lookup roll
+  pub roll: RollResult,
+
+  /// the entry text, with inline dice and nested tables already rolled
+  pub text: String,
+}
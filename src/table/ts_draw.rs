@@ -0,0 +1,167 @@
+#[test]
+fn weighted_toml() {
+  let t = super::Table::from_toml(
+    "treasure",
+    "entries = [\"x3: gold\", \"silver\", \"x2: gems\"]",
+  )
+  .unwrap();
+  assert_eq!(t.roll, "d6");
+  assert_eq!(t.lookup(1), Some("gold"));
+  assert_eq!(t.lookup(3), Some("gold"));
+  assert_eq!(t.lookup(4), Some("silver"));
+  assert_eq!(t.lookup(6), Some("gems"));
+}
+
+#[test]
+fn weighted_over_255() {
+  let mut tables = super::Tables::new();
+  tables
+    .insert(super::Table::from_toml("treasure", "entries = [\"x300: gold\", \"silver\"]").unwrap());
+  assert_eq!(tables.get("treasure").unwrap().roll, "d301");
+  assert!(tables.roll("treasure").is_ok());
+}
+
+#[test]
+fn weighted_csv() {
+  let t = super::Table::from_csv("treasure", "weight,treasure\nx2,gold\nsilver\n").unwrap();
+  assert_eq!(t.roll, "d3");
+  assert_eq!(t.lookup(2), Some("gold"));
+  assert_eq!(t.lookup(3), Some("silver"));
+}
+
+#[test]
+fn weighted_markdown() {
+  let t =
+    super::Table::from_markdown("treasure", "| treasure |\n|---|\n| gold |\n| silver |\n").unwrap();
+  assert_eq!(t.roll, "d2");
+  assert_eq!(t.lookup(2), Some("silver"));
+}
+
+#[test]
+fn mixed_entries() {
+  let t = super::Table::from_toml(
+    "treasure",
+    "roll = \"d6\"\nentries = [\"1-3: gold\", \"silver\"]",
+  );
+  assert_eq!(t, Err(super::TableErr::Parse));
+}
+
+#[test]
+fn distinct_draws() {
+  let mut tables = super::Tables::new();
+  tables.insert(
+    super::Table::from_toml("treasure", "entries = [\"gold\", \"silver\", \"gems\"]").unwrap(),
+  );
+  for _ in 1..=100 {
+    tables.reset("treasure");
+    let mut results: Vec<String> = tables
+      .draw("treasure", 3)
+      .unwrap()
+      .into_iter()
+      .map(|x| x.text)
+      .collect();
+    results.sort();
+    assert_eq!(results, vec!["gems", "gold", "silver"]);
+  }
+}
+
+#[test]
+fn exhausted() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_toml("treasure", "entries = [\"gold\", \"silver\"]").unwrap());
+  tables.draw("treasure", 2).unwrap();
+  assert!(tables.is_exhausted("treasure", "gold"));
+  assert_eq!(
+    tables.draw("treasure", 1).unwrap_err(),
+    super::TableErr::Exhausted
+  );
+  tables.reset("treasure");
+  assert!(!tables.is_exhausted("treasure", "gold"));
+  assert_eq!(tables.draw("treasure", 1).unwrap().len(), 1);
+}
+
+#[test]
+fn exhausted_between_runs() {
+  let path = std::env::temp_dir().join(format!("iron_dice_exhausted_{}.json", std::process::id()));
+
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_toml("treasure", "entries = [\"gold\", \"silver\"]").unwrap());
+  let drawn = tables.draw("treasure", 1).unwrap().pop().unwrap().text;
+  tables.save_exhausted(&path).unwrap();
+
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_toml("treasure", "entries = [\"gold\", \"silver\"]").unwrap());
+  tables.load_exhausted(&path).unwrap();
+  std::fs::remove_file(&path).unwrap();
+  assert!(tables.is_exhausted("treasure", &drawn));
+  assert_ne!(
+    tables.draw("treasure", 1).unwrap().pop().unwrap().text,
+    drawn
+  );
+}
+
+#[test]
+fn seeded_draws() {
+  let table = "entries = [\"a\", \"b\", \"c\", \"d\", \"e\", \"f\", \"g\", \"h\"]";
+  let mut t1 = super::Tables::new().seed(7);
+  let mut t2 = super::Tables::new().seed(7);
+  t1.insert(super::Table::from_toml("letters", table).unwrap());
+  t2.insert(super::Table::from_toml("letters", table).unwrap());
+  let d1: Vec<String> = t1
+    .draw("letters", 8)
+    .unwrap()
+    .into_iter()
+    .map(|x| x.text)
+    .collect();
+  let d2: Vec<String> = t2
+    .draw("letters", 8)
+    .unwrap()
+    .into_iter()
+    .map(|x| x.text)
+    .collect();
+  assert_eq!(d1, d2);
+}
+
+#[test]
+fn last_entry_left() {
+  let mut tables = super::Tables::new();
+  tables.insert(
+    super::Table::from_toml(
+      "wide",
+      "roll = \"d100\"\nentries = [\"1-99: common\", \"100: rare\"]",
+    )
+    .unwrap(),
+  );
+  assert_eq!(tables.draw("wide", 1).unwrap().len(), 1);
+  let last = tables.draw("wide", 1).unwrap().pop().unwrap();
+  assert!(last.text == "common" || last.text == "rare");
+  assert_eq!(
+    tables.draw("wide", 1).unwrap_err(),
+    super::TableErr::Exhausted
+  );
+}
+
+#[test]
+fn too_many_draws() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_toml("treasure", "entries = [\"gold\", \"silver\"]").unwrap());
+  assert_eq!(
+    tables.draw("treasure", 3).unwrap_err(),
+    super::TableErr::Exhausted
+  );
+  assert!(!tables.is_exhausted("treasure", "gold"));
+}
+
+#[test]
+fn draws_follow_the_roll() {
+  let table = "roll = \"2d6\"\nentries = [\"1: never\", \"2-12: always\", \"13-20: never\"]";
+  for seed in 1..=50 {
+    let mut tables = super::Tables::new().seed(seed);
+    tables.insert(super::Table::from_toml("odds", table).unwrap());
+    assert_eq!(tables.draw("odds", 1).unwrap()[0].text, "always");
+    assert_eq!(
+      tables.draw("odds", 1).unwrap_err(),
+      super::TableErr::Exhausted
+    );
+  }
+}
@@ -0,0 +1,85 @@
+#[test]
+fn toml() {
+  let t = super::Table::from_toml(
+    "wandering",
+    "roll = \"d100\"\nentries = [\"01-15: goblins\", \"16-100: 2d4 wolves\"]",
+  )
+  .unwrap();
+  assert_eq!(t.name, "wandering");
+  assert_eq!(t.roll, "d100");
+  assert_eq!(
+    t.entries[0],
+    super::TableEntry {
+      low: 1,
+      high: 15,
+      text: String::from("goblins")
+    }
+  );
+  assert_eq!(t.entries[1].text, "2d4 wolves");
+}
+
+#[test]
+fn toml_with_name() {
+  let t = super::Table::from_toml(
+    "file",
+    "name = \"weather\"\nroll = \"d6\"\nentries = [\"1-6: rain\"]",
+  )
+  .unwrap();
+  assert_eq!(t.name, "weather");
+}
+
+#[test]
+fn csv() {
+  let t = super::Table::from_csv(
+    "loot",
+    "2d6,loot\n2-6,copper\n7,silver, lots of it\n8-12,gold\n",
+  )
+  .unwrap();
+  assert_eq!(t.roll, "2d6");
+  assert_eq!(t.entries.len(), 3);
+  assert_eq!(t.entries[1].low, 7);
+  assert_eq!(t.entries[1].high, 7);
+  assert_eq!(t.entries[1].text, "silver, lots of it");
+}
+
+#[test]
+fn markdown() {
+  let t = super::Table::from_markdown(
+    "weather",
+    "# Weather\n\n| d6 | weather |\n|:---|---------|\n| 1-3 | rain |\n| 4-6 | sun |\n",
+  )
+  .unwrap();
+  assert_eq!(t.roll, "d6");
+  assert_eq!(t.entries.len(), 2);
+  assert_eq!(t.entries[1].low, 4);
+  assert_eq!(t.entries[1].text, "sun");
+}
+
+#[test]
+fn invalid_roll() {
+  let t = super::Table::from_csv("loot", "loot,text\n1,copper");
+  assert_eq!(t, Err(super::TableErr::InvalidRoll));
+}
+
+#[test]
+fn invalid_range() {
+  let t = super::Table::from_csv("loot", "d6,text\none,copper");
+  assert_eq!(t, Err(super::TableErr::Parse));
+}
+
+#[test]
+fn directory_with_a_bad_file() {
+  let path = std::env::temp_dir().join(format!("iron_dice_tables_{}", std::process::id()));
+  std::fs::create_dir_all(&path).unwrap();
+  std::fs::write(path.join("loot.toml"), "entries = [\"gold\", \"silver\"]").unwrap();
+  std::fs::write(path.join("broken.toml"), "entries = [").unwrap();
+
+  let mut tables = super::Tables::new();
+  let skipped = tables.load_dir(&path).unwrap();
+  std::fs::remove_dir_all(&path).unwrap();
+  assert_eq!(tables.names(), vec!["loot"]);
+  assert_eq!(
+    skipped,
+    vec![(path.join("broken.toml"), super::TableErr::Parse)]
+  );
+}
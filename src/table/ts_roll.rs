@@ -0,0 +1,76 @@
+#[test]
+fn lookup() {
+  let t = super::Table::from_csv("loot", "d6,loot\n1-3,copper\n4-6,silver").unwrap();
+  assert_eq!(t.lookup(1), Some("copper"));
+  assert_eq!(t.lookup(5), Some("silver"));
+  assert_eq!(t.lookup(7), None);
+}
+
+#[test]
+fn roll() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_csv("loot", "d6,loot\n1-3,copper\n4-6,silver").unwrap());
+  for _ in 1..=1000 {
+    let result = tables.roll("loot").unwrap();
+    assert_eq!(result.table, "loot");
+    match result.roll.outcome {
+      1..=3 => assert_eq!(result.text, "copper"),
+      _ => assert_eq!(result.text, "silver"),
+    }
+  }
+}
+
+#[test]
+fn inline_dice() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_csv("monsters", "d6,monster\n1-6,2d4 goblins").unwrap());
+  for _ in 1..=1000 {
+    let result = tables.roll("monsters").unwrap();
+    let (number, text) = result.text.split_once(' ').unwrap();
+    assert!((2..=8).contains(&number.parse::<i16>().unwrap()));
+    assert_eq!(text, "goblins");
+  }
+}
+
+#[test]
+fn nested_tables() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_csv("weather", "d6,weather\n1-6,rain").unwrap());
+  tables.insert(
+    super::Table::from_csv(
+      "encounter",
+      "d6,encounter\n1-6,goblins under the [[weather]]",
+    )
+    .unwrap(),
+  );
+  let result = tables.roll("encounter").unwrap();
+  assert_eq!(result.text, "goblins under the rain");
+}
+
+#[test]
+fn unknown_table() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_csv("encounter", "d6,encounter\n1-6,[[weather]]").unwrap());
+  let result = tables.roll("encounter");
+  assert_eq!(
+    result.unwrap_err(),
+    super::TableErr::UnknownTable(String::from("weather"))
+  );
+}
+
+#[test]
+fn no_entry() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_csv("loot", "d6,loot\n7,copper").unwrap());
+  assert!(matches!(
+    tables.roll("loot"),
+    Err(super::TableErr::NoEntry(_))
+  ));
+}
+
+#[test]
+fn recursion() {
+  let mut tables = super::Tables::new();
+  tables.insert(super::Table::from_csv("loop", "d6,loop\n1-6,[[loop]]").unwrap());
+  assert_eq!(tables.roll("loop").unwrap_err(), super::TableErr::Recursion);
+}